@@ -1,16 +1,17 @@
 //! This example uses the `Digit` drawable to draw a spinning throbber.
 //!
-//! The spinning progress indicator animation requires custom characters, which
-//! are defined using the `Segments` bitfield.
+//! The animation frames of the spinning progress indicators are generated by the
+//! `Spinner` iterator and contain custom characters, which are defined using the
+//! `Segments` bitfield.
 
-use std::{iter, time::Duration};
+use std::time::Duration;
 
-use eg_seven_segment::{Digit, Segments, SevenSegmentStyleBuilder};
+use eg_seven_segment::{Digit, Segments, SevenSegmentStyleBuilder, Spinner, SpinnerPattern};
 use embedded_graphics::{pixelcolor::Rgb888, prelude::*, text::renderer::TextRenderer};
 use embedded_graphics_simulator::{OutputSettings, SimulatorDisplay, SimulatorEvent, Window};
 
 fn main() -> Result<(), std::convert::Infallible> {
-    let mut display = SimulatorDisplay::<Rgb888>::new(Size::new(256, 256));
+    let mut display = SimulatorDisplay::<Rgb888>::new(Size::new(256, 400));
     let mut window = Window::new("Digit drawable", &OutputSettings::default());
 
     let style = SevenSegmentStyleBuilder::new()
@@ -21,34 +22,34 @@ fn main() -> Result<(), std::convert::Infallible> {
         .inactive_segment_color(Rgb888::new(0x30, 0x00, 0x00))
         .build();
 
-    // Create frame iterators for different types of progress indicators.
-    // The iterators are infinitely repeating and return animation frames of type `Segments`.
-    let small_top = Throbber::SmallTop.frames();
-    let small_bottom = Throbber::SmallBottom.frames();
-    let medium = Throbber::Medium.frames();
-    let large_1 = Throbber::Large1.frames();
-    let large_2 = Throbber::Large2.frames();
+    // Create spinners for different types of progress indicators.
+    // The spinners are infinitely repeating iterators and return animation frames, which contain
+    // the active segments of each digit.
+    let mut single_loop = Spinner::<1>::new(SpinnerPattern::Loop);
+    let mut figure_eight = Spinner::<1>::new(SpinnerPattern::FigureEight);
+    let mut large_loop = Spinner::<2>::new(SpinnerPattern::Loop);
+    let mut snake = Spinner::<3>::new(SpinnerPattern::Snake);
+    let mut bouncing_bar = Spinner::<3>::new(SpinnerPattern::BouncingBar);
 
-    let throbbers = &mut [
-        &mut [small_top] as &mut [_],
-        &mut [small_bottom],
-        &mut [medium],
-        &mut [large_1, large_2],
-    ];
-
-    let start_position = Point::new(100, 25);
+    let start_position = Point::new(60, 25);
 
     'main: loop {
-        let mut position = start_position;
+        // Get active segments for next animation frame.
+        let lines: [&[Segments]; 5] = [
+            &single_loop.next().unwrap(),
+            &figure_eight.next().unwrap(),
+            &large_loop.next().unwrap(),
+            &snake.next().unwrap(),
+            &bouncing_bar.next().unwrap(),
+        ];
 
-        for line in throbbers.iter_mut() {
-            for throbber in line.iter_mut() {
-                // Get active segments for next animation frame.
-                let segments = throbber.next().unwrap();
+        let mut position = start_position;
 
+        for line in lines {
+            for segments in line {
                 // Draw the digit at `position`.
                 // The returned `Point` is the position of the next digit in the same line.
-                position = Digit::new(segments, position)
+                position = Digit::new(*segments, position)
                     .into_styled(style)
                     .draw(&mut display)?;
             }
@@ -69,52 +70,3 @@ fn main() -> Result<(), std::convert::Infallible> {
 
     Ok(())
 }
-
-pub enum Throbber {
-    SmallTop,
-    SmallBottom,
-    Medium,
-    Large1,
-    Large2,
-}
-
-impl Throbber {
-    fn frames(self) -> impl Iterator<Item = Segments> {
-        const NONE: Segments = Segments::empty();
-
-        let frames: &[_] = match self {
-            Throbber::SmallTop => &[Segments::A, Segments::B, Segments::G, Segments::F],
-            Throbber::SmallBottom => &[Segments::C, Segments::D, Segments::E, Segments::G],
-            Throbber::Medium => &[
-                Segments::A,
-                Segments::B,
-                Segments::C,
-                Segments::D,
-                Segments::E,
-                Segments::F,
-            ],
-            Throbber::Large1 => &[
-                Segments::A,
-                NONE,
-                NONE,
-                NONE,
-                NONE,
-                Segments::D,
-                Segments::E,
-                Segments::F,
-            ],
-            Throbber::Large2 => &[
-                NONE,
-                Segments::A,
-                Segments::B,
-                Segments::C,
-                Segments::D,
-                NONE,
-                NONE,
-                NONE,
-            ],
-        };
-
-        iter::repeat(frames.iter().copied()).flatten()
-    }
-}
@@ -1,12 +1,8 @@
 use std::time::Duration;
 
 use chrono::prelude::*;
-use eg_seven_segment::SevenSegmentStyleBuilder;
-use embedded_graphics::{
-    pixelcolor::BinaryColor,
-    prelude::*,
-    text::{Alignment, Baseline, Text, TextStyleBuilder},
-};
+use eg_seven_segment::{ClockDisplay, SevenSegmentStyleBuilder};
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, primitives::StyledDrawable};
 use embedded_graphics_simulator::{
     BinaryColorTheme, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
@@ -19,20 +15,24 @@ where
         .segment_color(BinaryColor::On)
         .build();
 
-    let text_style = TextStyleBuilder::new()
-        .alignment(Alignment::Center)
-        .baseline(Baseline::Middle)
-        .build();
+    let now = Local::now();
+    let clock = ClockDisplay::new(
+        now.hour() as u8,
+        now.minute() as u8,
+        now.second() as u8,
+        Point::zero(),
+    )
+    .with_seconds(true)
+    .with_colon_blink(true);
 
-    let time = Local::now().format("%H:%M:%S").to_string();
+    // Center the clock on the display.
+    let center = clock.bounding_box(&character_style).center();
+    let clock = ClockDisplay {
+        position: display.bounding_box().center() - center,
+        ..clock
+    };
 
-    Text::with_text_style(
-        &time,
-        display.bounding_box().center(),
-        character_style,
-        text_style,
-    )
-    .draw(display)?;
+    clock.draw_styled(&character_style, display)?;
 
     Ok(())
 }
@@ -50,7 +50,7 @@ fn main() {
         display.clear(BinaryColor::Off).unwrap();
         draw_clock(&mut display).unwrap();
 
-        window.update(&mut display);
+        window.update(&display);
 
         if window.events().any(|event| event == SimulatorEvent::Quit) {
             break;
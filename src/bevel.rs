@@ -0,0 +1,26 @@
+/// Bevel effect.
+///
+/// The bevel effect gives segments a three dimensional look by drawing the top and left edges
+/// of each segment in a lighter color and the bottom and right edges in a darker color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bevel<C> {
+    /// The width of the beveled edges.
+    pub width: u32,
+
+    /// The color of the top and left edges.
+    pub light_color: C,
+
+    /// The color of the bottom and right edges.
+    pub dark_color: C,
+}
+
+impl<C> Bevel<C> {
+    /// Creates a new bevel effect.
+    pub fn new(width: u32, light_color: C, dark_color: C) -> Self {
+        Self {
+            width,
+            light_color,
+            dark_color,
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use embedded_graphics::{prelude::*, text::Baseline};
+
+use crate::{glyph::Glyph, Segments, SevenSegmentStyle};
+
+/// Blink timing.
+///
+/// The blink timing defines when blinking characters and segments are visible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Blink {
+    /// The blink period in milliseconds.
+    pub period_ms: u32,
+
+    /// The duty cycle in percent.
+    ///
+    /// The duty cycle is the part of each period in which blinking elements are visible.
+    pub duty_cycle: u8,
+}
+
+impl Blink {
+    /// Creates a new blink timing.
+    pub const fn new(period_ms: u32, duty_cycle: u8) -> Self {
+        Self {
+            period_ms,
+            duty_cycle,
+        }
+    }
+
+    /// Returns `true` if blinking elements are visible at the given time.
+    ///
+    /// Each period starts with the visible phase. A period of `0` disables blinking.
+    pub fn is_visible(&self, time_ms: u32) -> bool {
+        if self.period_ms == 0 {
+            return true;
+        }
+
+        let phase = u64::from(time_ms % self.period_ms);
+
+        phase * 100 < u64::from(self.period_ms) * u64::from(self.duty_cycle.min(100))
+    }
+}
+
+impl Default for Blink {
+    /// Returns a blink timing with a period of one second and a duty cycle of 50%.
+    fn default() -> Self {
+        Self::new(1000, 50)
+    }
+}
+
+/// Text with blinking characters and segments.
+///
+/// The blink mask defines which parts of the text are blinking. Each entry in the mask contains
+/// the blinking segments of the character at the same position in the text. Blinking segments
+/// are drawn as inactive segments while they are hidden. Colons and decimal points blink if
+/// their mask entry isn't empty. Characters without a mask entry don't blink.
+///
+/// Unsupported characters are drawn according to the
+/// [`unsupported_character`](SevenSegmentStyle::unsupported_character) policy of the style.
+/// The mask entries still refer to the characters in the text, including skipped characters.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888};
+/// use eg_seven_segment::{Blink, BlinkingText, Segments, SevenSegmentStyleBuilder};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// // Blink the minutes of a clock.
+/// let mask = [
+///     Segments::empty(),
+///     Segments::empty(),
+///     Segments::empty(),
+///     Segments::all(),
+///     Segments::all(),
+/// ];
+///
+/// let time_ms = 1234;
+/// BlinkingText::new("12:42", Point::new(5, 30), style, &mask)
+///     .with_blink(Blink::new(500, 50))
+///     .draw_at(time_ms, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlinkingText<'a, C> {
+    /// The text.
+    pub text: &'a str,
+
+    /// The position.
+    pub position: Point,
+
+    /// The character style.
+    pub style: SevenSegmentStyle<C>,
+
+    /// The baseline.
+    pub baseline: Baseline,
+
+    /// The blink mask.
+    pub blink_mask: &'a [Segments],
+
+    /// The blink timing.
+    pub blink: Blink,
+}
+
+impl<'a, C: PixelColor> BlinkingText<'a, C> {
+    /// Creates a new blinking text.
+    ///
+    /// The text uses the alphabetic baseline and the default blink timing.
+    pub fn new(
+        text: &'a str,
+        position: Point,
+        style: SevenSegmentStyle<C>,
+        blink_mask: &'a [Segments],
+    ) -> Self {
+        Self {
+            text,
+            position,
+            style,
+            baseline: Baseline::Alphabetic,
+            blink_mask,
+            blink: Blink::default(),
+        }
+    }
+
+    /// Sets the baseline.
+    pub fn with_baseline(mut self, baseline: Baseline) -> Self {
+        self.baseline = baseline;
+
+        self
+    }
+
+    /// Sets the blink timing.
+    pub fn with_blink(mut self, blink: Blink) -> Self {
+        self.blink = blink;
+
+        self
+    }
+}
+
+impl<C: PixelColor> BlinkingText<'_, C> {
+    /// Draws the text at the given point in time.
+    ///
+    /// Returns the position of the next character.
+    pub fn draw_at<D>(&self, time_ms: u32, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let visible = self.blink.is_visible(time_ms);
+        let policy = self.style.unsupported_character;
+
+        let glyphs = self.text.chars().enumerate().filter_map(|(index, c)| {
+            let hidden = self
+                .blink_mask
+                .get(index)
+                .copied()
+                .filter(|_| !visible)
+                .unwrap_or(Segments::empty());
+
+            policy.apply(Glyph::from(c)).map(|glyph| (glyph, hidden))
+        });
+
+        self.style
+            .draw_glyphs(glyphs, self.position, self.baseline, target)
+    }
+}
+
+impl<C: PixelColor> Drawable for BlinkingText<'_, C> {
+    type Color = C;
+    type Output = Point;
+
+    /// Draws the text with all blinking elements visible.
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let glyphs = self
+            .style
+            .string_glyphs(self.text)
+            .map(|glyph| (glyph, Segments::empty()));
+
+        self.style
+            .draw_glyphs(glyphs, self.position, self.baseline, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SevenSegmentStyleBuilder, UnsupportedCharacterPolicy};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    fn test_blink(time_ms: u32, expected_pattern: &[&str]) {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 7))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(BinaryColor::On)
+            .inactive_segment_color(BinaryColor::Off)
+            .build();
+
+        let mask = [Segments::empty(), Segments::all(), Segments::G];
+
+        let mut display = MockDisplay::new();
+        BlinkingText::new("1:8", Point::zero(), style, &mask)
+            .with_baseline(Baseline::Top)
+            .with_blink(Blink::new(1000, 25))
+            .draw_at(time_ms, &mut display)
+            .unwrap();
+
+        display.assert_pattern(expected_pattern);
+    }
+
+    #[test]
+    fn visible() {
+        test_blink(
+            1249,
+            &[
+                " ...     ### ", //
+                ".   #   #   #", //
+                ".   # # #   #", //
+                " ...     ### ", //
+                ".   # # #   #", //
+                ".   #   #   #", //
+                " ...     ### ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn hidden() {
+        test_blink(
+            1250,
+            &[
+                " ...     ### ", //
+                ".   #   #   #", //
+                ".   #   #   #", //
+                " ...     ... ", //
+                ".   #   #   #", //
+                ".   #   #   #", //
+                " ...     ### ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn unsupported_characters() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 7))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(BinaryColor::On)
+            .unsupported_character(UnsupportedCharacterPolicy::Skip)
+            .build();
+
+        // The mask entry of the skipped character is ignored.
+        let mask = [Segments::empty(), Segments::all(), Segments::G];
+        let text = BlinkingText::new("1%8", Point::zero(), style, &mask)
+            .with_baseline(Baseline::Top)
+            .with_blink(Blink::new(1000, 25));
+
+        let mut display = MockDisplay::new();
+        text.draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "       ### ", //
+            "    # #   #", //
+            "    # #   #", //
+            "       ### ", //
+            "    # #   #", //
+            "    # #   #", //
+            "       ### ", //
+        ]);
+
+        let mut display = MockDisplay::new();
+        text.draw_at(250, &mut display).unwrap();
+        display.assert_pattern(&[
+            "       ### ", //
+            "    # #   #", //
+            "    # #   #", //
+            "           ", //
+            "    # #   #", //
+            "    # #   #", //
+            "       ### ", //
+        ]);
+    }
+
+    #[test]
+    fn is_visible() {
+        let blink = Blink::new(100, 30);
+        assert!(blink.is_visible(0));
+        assert!(blink.is_visible(29));
+        assert!(!blink.is_visible(30));
+        assert!(!blink.is_visible(99));
+        assert!(blink.is_visible(100));
+
+        assert!(Blink::new(0, 0).is_visible(10));
+        assert!(!Blink::new(100, 0).is_visible(0));
+        assert!(Blink::new(100, 100).is_visible(99));
+    }
+}
@@ -0,0 +1,348 @@
+use core::convert::TryFrom;
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+    text::Baseline,
+};
+
+use crate::{
+    glyph::Glyph,
+    glyph_buffer::{GlyphBuffer, Padding},
+    LeadingZeros, Segments, SevenSegmentStyle,
+};
+
+/// Hour format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum HourFormat {
+    /// 24-hour format.
+    #[default]
+    TwentyFourHour,
+
+    /// 12-hour format.
+    TwelveHour,
+}
+
+/// AM/PM indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AmPmIndicator {
+    /// An additional digit which shows `A` or `P`.
+    Letter,
+
+    /// A decimal point after the time, which is lit in the afternoon.
+    DecimalPoint,
+}
+
+/// Clock.
+///
+/// The clock displays the time in the format `HH:MM` or `HH:MM:SS`. Out of range values are
+/// wrapped, e.g. an hour value of `25` is displayed as `01`.
+///
+/// The position is the top left corner of the first digit.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{
+///     AmPmIndicator, ClockDisplay, HourFormat, LeadingZeros, SevenSegmentStyleBuilder,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// // Draws " 9:41P" with a blinking colon.
+/// ClockDisplay::new(21, 41, 7, Point::new(5, 5))
+///     .with_hour_format(HourFormat::TwelveHour)
+///     .with_leading_zeros(LeadingZeros::Suppress)
+///     .with_am_pm_indicator(AmPmIndicator::Letter)
+///     .with_colon_blink(true)
+///     .draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClockDisplay {
+    /// The hours.
+    pub hours: u8,
+
+    /// The minutes.
+    pub minutes: u8,
+
+    /// The seconds.
+    pub seconds: u8,
+
+    /// The position.
+    pub position: Point,
+
+    /// The hour format.
+    pub hour_format: HourFormat,
+
+    /// The display mode of the leading zero of hours less than ten.
+    pub leading_zeros: LeadingZeros,
+
+    /// Show the seconds.
+    pub show_seconds: bool,
+
+    /// Blink the colons.
+    ///
+    /// If enabled, the colons are hidden in odd seconds.
+    pub colon_blink: bool,
+
+    /// The AM/PM indicator.
+    ///
+    /// The indicator is only shown in the 12-hour format.
+    pub am_pm_indicator: Option<AmPmIndicator>,
+}
+
+/// The maximum number of glyphs in a clock.
+const CLOCK_GLYPHS: usize = 9;
+
+impl ClockDisplay {
+    /// Creates a new clock.
+    ///
+    /// The clock uses the 24-hour format with leading zeros and doesn't show the seconds.
+    /// Unlike [`NumberDisplay`](crate::NumberDisplay) the clock shows leading zeros by
+    /// default, because times are usually written with them.
+    pub fn new(hours: u8, minutes: u8, seconds: u8, position: Point) -> Self {
+        Self {
+            hours,
+            minutes,
+            seconds,
+            position,
+            hour_format: HourFormat::default(),
+            leading_zeros: LeadingZeros::Show,
+            show_seconds: false,
+            colon_blink: false,
+            am_pm_indicator: None,
+        }
+    }
+
+    /// Sets the hour format.
+    pub fn with_hour_format(mut self, hour_format: HourFormat) -> Self {
+        self.hour_format = hour_format;
+
+        self
+    }
+
+    /// Sets the display mode of the leading hour zero.
+    pub fn with_leading_zeros(mut self, leading_zeros: LeadingZeros) -> Self {
+        self.leading_zeros = leading_zeros;
+
+        self
+    }
+
+    /// Shows or hides the seconds.
+    pub fn with_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+
+        self
+    }
+
+    /// Enables or disables colon blinking.
+    pub fn with_colon_blink(mut self, colon_blink: bool) -> Self {
+        self.colon_blink = colon_blink;
+
+        self
+    }
+
+    /// Sets the AM/PM indicator.
+    pub fn with_am_pm_indicator(mut self, am_pm_indicator: AmPmIndicator) -> Self {
+        self.am_pm_indicator = Some(am_pm_indicator);
+
+        self
+    }
+
+    /// Returns the bounding box of the clock.
+    pub fn bounding_box<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> Rectangle {
+        self.glyphs().bounding_box(self.position, style)
+    }
+
+    /// Formats the clock.
+    fn glyphs(&self) -> GlyphBuffer<CLOCK_GLYPHS> {
+        let mut glyphs = GlyphBuffer::new();
+
+        let hours = self.hours % 24;
+        let pm = hours >= 12;
+        let display_hours = match self.hour_format {
+            HourFormat::TwentyFourHour => hours,
+            HourFormat::TwelveHour if hours.is_multiple_of(12) => 12,
+            HourFormat::TwelveHour => hours % 12,
+        };
+
+        let colon_hidden = if self.colon_blink && self.seconds % 2 == 1 {
+            Segments::all()
+        } else {
+            Segments::empty()
+        };
+
+        glyphs.push_number(display_hours.into(), 10, 2, self.leading_zeros.padding());
+        glyphs.push_hidden(Glyph::Colon, colon_hidden);
+        glyphs.push_number((self.minutes % 60).into(), 10, 2, Padding::Zeros);
+
+        if self.show_seconds {
+            glyphs.push_hidden(Glyph::Colon, colon_hidden);
+            glyphs.push_number((self.seconds % 60).into(), 10, 2, Padding::Zeros);
+        }
+
+        match self.am_pm_indicator {
+            _ if self.hour_format == HourFormat::TwentyFourHour => {}
+            Some(AmPmIndicator::Letter) => {
+                let letter = if pm { 'P' } else { 'A' };
+                glyphs.push(Glyph::Digit(Segments::try_from(letter).unwrap()));
+            }
+            Some(AmPmIndicator::DecimalPoint) => {
+                let hidden = if pm {
+                    Segments::empty()
+                } else {
+                    Segments::all()
+                };
+                glyphs.push_hidden(Glyph::DecimalPoint, hidden);
+            }
+            None => {}
+        }
+
+        glyphs
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for ClockDisplay {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        style.draw_glyphs(self.glyphs().iter(), self.position, Baseline::Top, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_glyphs, style};
+    use embedded_graphics::mock_display::MockDisplay;
+
+    fn assert_clock(clock: ClockDisplay, expected: &str) {
+        assert_glyphs(clock.glyphs().iter(), expected);
+    }
+
+    #[test]
+    fn twenty_four_hour() {
+        let clock = ClockDisplay::new(9, 5, 0, Point::zero());
+
+        assert_clock(clock, "09:05");
+        assert_clock(clock.with_seconds(true), "09:05:00");
+        assert_clock(clock.with_leading_zeros(LeadingZeros::Inactive), " 9:05");
+        assert_eq!(
+            clock
+                .with_leading_zeros(LeadingZeros::Suppress)
+                .glyphs()
+                .iter()
+                .next(),
+            Some((Glyph::Empty, Segments::empty()))
+        );
+        assert_clock(ClockDisplay::new(23, 59, 58, Point::zero()), "23:59");
+        assert_clock(ClockDisplay::new(25, 61, 0, Point::zero()), "01:01");
+    }
+
+    #[test]
+    fn twelve_hour() {
+        let clock = |hours| {
+            ClockDisplay::new(hours, 30, 0, Point::zero())
+                .with_hour_format(HourFormat::TwelveHour)
+                .with_leading_zeros(LeadingZeros::Inactive)
+                .with_am_pm_indicator(AmPmIndicator::Letter)
+        };
+
+        assert_clock(clock(0), "12:30A");
+        assert_clock(clock(1), " 1:30A");
+        assert_clock(clock(11), "11:30A");
+        assert_clock(clock(12), "12:30P");
+        assert_clock(clock(13), " 1:30P");
+        assert_clock(clock(23), "11:30P");
+    }
+
+    #[test]
+    fn am_pm_decimal_point() {
+        let clock = ClockDisplay::new(8, 0, 0, Point::zero())
+            .with_hour_format(HourFormat::TwelveHour)
+            .with_am_pm_indicator(AmPmIndicator::DecimalPoint);
+
+        assert_eq!(
+            clock.glyphs().iter().last(),
+            Some((Glyph::DecimalPoint, Segments::all()))
+        );
+
+        let clock = ClockDisplay { hours: 20, ..clock };
+        assert_clock(clock, "08:00.");
+    }
+
+    #[test]
+    fn am_pm_indicator_in_24_hour_format() {
+        let clock =
+            ClockDisplay::new(20, 0, 0, Point::zero()).with_am_pm_indicator(AmPmIndicator::Letter);
+
+        assert_clock(clock, "20:00");
+    }
+
+    #[test]
+    fn colon_blink() {
+        let clock = ClockDisplay::new(12, 34, 56, Point::zero())
+            .with_seconds(true)
+            .with_colon_blink(true);
+
+        assert_clock(clock, "12:34:56");
+
+        let clock = ClockDisplay {
+            seconds: 57,
+            ..clock
+        };
+        let glyphs = clock.glyphs();
+        let colons = glyphs
+            .iter()
+            .filter(|(glyph, _)| *glyph == Glyph::Colon)
+            .map(|(_, hidden)| hidden);
+        assert!(colons.eq([Segments::all(), Segments::all()]));
+    }
+
+    #[test]
+    fn draw() {
+        let style = style();
+
+        let clock = ClockDisplay::new(1, 23, 1, Point::zero())
+            .with_leading_zeros(LeadingZeros::Inactive)
+            .with_colon_blink(true);
+
+        assert_eq!(
+            clock.bounding_box(&style),
+            Rectangle::new(Point::zero(), Size::new(25, 9))
+        );
+
+        let mut display = MockDisplay::new();
+        let next = clock.draw_styled(&style, &mut display).unwrap();
+        assert_eq!(next, Point::new(26, 0));
+
+        display.assert_pattern(&[
+            "               ###   ### ", //
+            "          #       #     #", //
+            "          #       #     #", //
+            "          #       #     #", //
+            "               ###   ### ", //
+            "          #   #         #", //
+            "          #   #         #", //
+            "          #   #         #", //
+            "               ###   ### ", //
+        ]);
+    }
+}
@@ -0,0 +1,380 @@
+use core::convert::TryFrom;
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+    text::Baseline,
+};
+
+use crate::{
+    glyph::Glyph,
+    glyph_buffer::{GlyphBuffer, Padding},
+    Segments, SevenSegmentStyle,
+};
+
+/// Order of the date fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DateOrder {
+    /// Year, month and day, e.g. `2026-10-16`.
+    #[default]
+    YearMonthDay,
+
+    /// Day, month and year, e.g. `16.10.2026`.
+    DayMonthYear,
+
+    /// Month, day and year, e.g. `10-16-2026`.
+    MonthDayYear,
+
+    /// Day and month, e.g. `16.10.`.
+    DayMonth,
+
+    /// Month and day, e.g. `10-16`.
+    MonthDay,
+}
+
+impl DateOrder {
+    /// Returns the fields in display order.
+    fn fields(self) -> &'static [DateField] {
+        use DateField::*;
+
+        match self {
+            DateOrder::YearMonthDay => &[Year, Month, Day],
+            DateOrder::DayMonthYear => &[Day, Month, Year],
+            DateOrder::MonthDayYear => &[Month, Day, Year],
+            DateOrder::DayMonth => &[Day, Month],
+            DateOrder::MonthDay => &[Month, Day],
+        }
+    }
+}
+
+/// Date field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateField {
+    Year,
+    Month,
+    Day,
+}
+
+/// Separator between date fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DateSeparator {
+    /// A digit with a `-` sign.
+    #[default]
+    Dash,
+
+    /// A decimal point.
+    ///
+    /// The decimal point only uses the width of a segment, which makes it a good choice for
+    /// narrow displays.
+    DecimalPoint,
+}
+
+/// Number of year digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum YearDigits {
+    /// Four digits, e.g. `2026`.
+    #[default]
+    Four,
+
+    /// Two digits, e.g. `26`.
+    Two,
+}
+
+/// Date.
+///
+/// The date widget displays a date with a configurable field order and separator. Month and
+/// day are always displayed with two digits. The values aren't validated, e.g. a month value of
+/// `13` is displayed as is.
+///
+/// If a maximum width is set, the date is shortened to fit into the available space by first
+/// reducing the year to two digits and then omitting the year. Dates which don't fit even
+/// without the year aren't drawn.
+///
+/// The position is the top left corner of the first digit.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{
+///     DateDisplay, DateOrder, DateSeparator, SevenSegmentStyleBuilder,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// // Draws "16.10.".
+/// DateDisplay::new(2026, 10, 16, Point::new(5, 5))
+///     .with_order(DateOrder::DayMonth)
+///     .with_separator(DateSeparator::DecimalPoint)
+///     .with_trailing_separator(true)
+///     .draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateDisplay {
+    /// The year.
+    pub year: u16,
+
+    /// The month.
+    pub month: u8,
+
+    /// The day.
+    pub day: u8,
+
+    /// The position.
+    pub position: Point,
+
+    /// The field order.
+    pub order: DateOrder,
+
+    /// The separator between fields.
+    pub separator: DateSeparator,
+
+    /// Add a separator after the last field.
+    pub trailing_separator: bool,
+
+    /// The number of year digits.
+    pub year_digits: YearDigits,
+
+    /// The maximum width in pixels.
+    pub max_width: Option<u32>,
+}
+
+/// The maximum number of glyphs in a date.
+const DATE_GLYPHS: usize = 11;
+
+impl DateDisplay {
+    /// Creates a new date.
+    pub fn new(year: u16, month: u8, day: u8, position: Point) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            position,
+            order: DateOrder::default(),
+            separator: DateSeparator::default(),
+            trailing_separator: false,
+            year_digits: YearDigits::default(),
+            max_width: None,
+        }
+    }
+
+    /// Sets the field order.
+    pub fn with_order(mut self, order: DateOrder) -> Self {
+        self.order = order;
+
+        self
+    }
+
+    /// Sets the separator.
+    pub fn with_separator(mut self, separator: DateSeparator) -> Self {
+        self.separator = separator;
+
+        self
+    }
+
+    /// Enables or disables the separator after the last field.
+    pub fn with_trailing_separator(mut self, trailing_separator: bool) -> Self {
+        self.trailing_separator = trailing_separator;
+
+        self
+    }
+
+    /// Sets the number of year digits.
+    pub fn with_year_digits(mut self, year_digits: YearDigits) -> Self {
+        self.year_digits = year_digits;
+
+        self
+    }
+
+    /// Sets the maximum width in pixels.
+    pub fn with_max_width(mut self, max_width: u32) -> Self {
+        self.max_width = Some(max_width);
+
+        self
+    }
+
+    /// Returns the bounding box of the date.
+    ///
+    /// A zero sized bounding box is returned if the date doesn't fit into the maximum width.
+    pub fn bounding_box<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> Rectangle {
+        self.fitting_glyphs(style)
+            .map(|glyphs| glyphs.bounding_box(self.position, style))
+            .unwrap_or(Rectangle::new(self.position, Size::zero()))
+    }
+
+    /// Returns the longest representation of the date which fits into the maximum width.
+    fn fitting_glyphs<C: PixelColor>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+    ) -> Option<GlyphBuffer<DATE_GLYPHS>> {
+        let candidates = [Some(self.year_digits), Some(YearDigits::Two), None];
+
+        candidates
+            .iter()
+            .map(|year_digits| self.glyphs(*year_digits))
+            .find(|glyphs| {
+                self.max_width.is_none_or(|max_width| {
+                    glyphs.bounding_box(self.position, style).size.width <= max_width
+                })
+            })
+    }
+
+    /// Formats the date.
+    ///
+    /// The year is omitted if `year_digits` is `None`.
+    fn glyphs(&self, year_digits: Option<YearDigits>) -> GlyphBuffer<DATE_GLYPHS> {
+        let mut glyphs = GlyphBuffer::new();
+
+        let separator = match self.separator {
+            DateSeparator::Dash => Glyph::Digit(Segments::try_from('-').unwrap()),
+            DateSeparator::DecimalPoint => Glyph::DecimalPoint,
+        };
+
+        let fields = self
+            .order
+            .fields()
+            .iter()
+            .filter(|field| year_digits.is_some() || **field != DateField::Year);
+
+        for (index, field) in fields.enumerate() {
+            if index > 0 {
+                glyphs.push(separator);
+            }
+
+            match field {
+                DateField::Year => match year_digits {
+                    Some(YearDigits::Two) => {
+                        glyphs.push_number(u32::from(self.year % 100), 10, 2, Padding::Zeros);
+                    }
+                    _ => glyphs.push_number(u32::from(self.year % 10000), 10, 4, Padding::Zeros),
+                },
+                DateField::Month => {
+                    glyphs.push_number(u32::from(self.month % 100), 10, 2, Padding::Zeros);
+                }
+                DateField::Day => {
+                    glyphs.push_number(u32::from(self.day % 100), 10, 2, Padding::Zeros);
+                }
+            }
+        }
+
+        if self.trailing_separator {
+            glyphs.push(separator);
+        }
+
+        glyphs
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for DateDisplay {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        match self.fitting_glyphs(style) {
+            Some(glyphs) => style.draw_glyphs(glyphs.iter(), self.position, Baseline::Top, target),
+            None => Ok(self.position),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_glyphs, style};
+    use embedded_graphics::mock_display::MockDisplay;
+
+    fn assert_date(date: DateDisplay, expected: &str) {
+        match date.fitting_glyphs(&style()) {
+            Some(glyphs) => assert_glyphs(glyphs.iter(), expected),
+            None => panic!("expected {:?}", expected),
+        }
+    }
+
+    #[test]
+    fn orders() {
+        let date = DateDisplay::new(2026, 10, 6, Point::zero());
+
+        assert_date(date, "2026-10-06");
+        assert_date(date.with_order(DateOrder::DayMonthYear), "06-10-2026");
+        assert_date(date.with_order(DateOrder::MonthDayYear), "10-06-2026");
+        assert_date(date.with_order(DateOrder::DayMonth), "06-10");
+        assert_date(date.with_order(DateOrder::MonthDay), "10-06");
+    }
+
+    #[test]
+    fn separators() {
+        let date = DateDisplay::new(2026, 10, 16, Point::zero())
+            .with_order(DateOrder::DayMonthYear)
+            .with_separator(DateSeparator::DecimalPoint);
+
+        assert_date(date, "16.10.2026");
+        assert_date(
+            date.with_order(DateOrder::DayMonth)
+                .with_trailing_separator(true),
+            "16.10.",
+        );
+        assert_date(date.with_year_digits(YearDigits::Two), "16.10.26");
+    }
+
+    #[test]
+    fn max_width() {
+        // Digits are 5 pixels wide, decimal points 1 pixel and the spacing is 1 pixel.
+        let date = DateDisplay::new(2026, 10, 16, Point::zero())
+            .with_order(DateOrder::DayMonthYear)
+            .with_separator(DateSeparator::DecimalPoint);
+
+        assert_date(date.with_max_width(51), "16.10.2026");
+        assert_date(date.with_max_width(50), "16.10.26");
+        assert_date(date.with_max_width(39), "16.10.26");
+        assert_date(date.with_max_width(38), "16.10");
+        assert_date(date.with_max_width(25), "16.10");
+
+        let date = date.with_max_width(24);
+        assert!(date.fitting_glyphs(&style()).is_none());
+        assert_eq!(
+            date.bounding_box(&style()),
+            Rectangle::new(Point::zero(), Size::zero())
+        );
+
+        let mut display = MockDisplay::new();
+        date.draw_styled(&style(), &mut display).unwrap();
+        display.assert_pattern(&[]);
+    }
+
+    #[test]
+    fn draw() {
+        let date = DateDisplay::new(2026, 1, 2, Point::zero())
+            .with_order(DateOrder::MonthDay)
+            .with_separator(DateSeparator::DecimalPoint);
+
+        let mut display = MockDisplay::new();
+        date.draw_styled(&style(), &mut display).unwrap();
+
+        display.assert_pattern(&[
+            " ###           ###   ### ", //
+            "#   #     #   #   #     #", //
+            "#   #     #   #   #     #", //
+            "#   #     #   #   #     #", //
+            "                     ### ", //
+            "#   #     #   #   # #    ", //
+            "#   #     #   #   # #    ", //
+            "#   #     #   #   # #    ", //
+            " ###        #  ###   ### ", //
+        ]);
+    }
+}
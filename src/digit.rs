@@ -4,19 +4,41 @@ use embedded_graphics::{
     primitives::{Rectangle, Styled, StyledDrawable},
 };
 
-use crate::{segment::Segment, Segments, SevenSegmentStyle};
+use crate::{segment::Segment, Bevel, Segments, SevenSegmentStyle};
 
 /// Single digit drawable.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Digit {
     segments: Segments,
     position: Point,
+    brightness: u8,
 }
 
 impl Digit {
     /// Creates a new digit.
     pub fn new(segments: Segments, position: Point) -> Self {
-        Self { segments, position }
+        Self {
+            segments,
+            position,
+            brightness: u8::MAX,
+        }
+    }
+
+    /// Sets the brightness of this digit.
+    ///
+    /// The digit brightness is combined with the brightness of the style. The default brightness
+    /// is `255`, which draws the digit with the unchanged style brightness.
+    ///
+    /// Like the style brightness, the digit brightness has no effect for styles created with
+    /// [`SevenSegmentStyleBuilder::without_interpolation`](crate::SevenSegmentStyleBuilder::without_interpolation).
+    ///
+    /// Text drawn with the [`TextRenderer`](embedded_graphics::text::renderer::TextRenderer)
+    /// implementation of the style can't set a per-digit brightness and only uses the style
+    /// brightness.
+    pub fn with_brightness(mut self, brightness: u8) -> Self {
+        self.brightness = brightness;
+
+        self
     }
 
     /// Applies a style to this digit.
@@ -43,65 +65,210 @@ impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for Digit {
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        let rect = Rectangle::new(self.position, style.digit_size);
-
-        let vertical_size = Size::new(style.digit_size.width, style.segment_width);
-        let horizontal_size_top = Size::new(
-            style.segment_width,
-            (style.digit_size.height + style.segment_width) / 2,
-        );
-        let horizontal_size_bottom = Size::new(
-            style.segment_width,
-            (style.digit_size.height + style.segment_width + 1) / 2,
-        );
-
-        if let Some(color) = style.state_color(self.segments.contains(Segments::A)) {
-            Segment::with_reduced_size(rect.resized(vertical_size, AnchorPoint::TopLeft), color)
-                .draw(target)?;
-        }
+        let cell = Rectangle::new(self.position, style.digit_size);
 
-        if let Some(color) = style.state_color(self.segments.contains(Segments::B)) {
-            Segment::with_reduced_size(
-                rect.resized(horizontal_size_top, AnchorPoint::TopRight),
-                color,
-            )
-            .draw(target)?;
-        }
+        style.draw_background(self.position, style.digit_size.width, target)?;
 
-        if let Some(color) = style.state_color(self.segments.contains(Segments::C)) {
-            Segment::with_reduced_size(
-                rect.resized(horizontal_size_bottom, AnchorPoint::BottomRight),
-                color,
-            )
-            .draw(target)?;
-        }
+        self.draw_in_text(style, cell, 0, target)
+    }
+}
+
+impl Digit {
+    /// Draws the digit as part of a string.
+    ///
+    /// `text` is the bounding box of the string and `index` is the position of the digit inside
+    /// the string. The background isn't drawn, because it needs to be drawn for all characters
+    /// of the string before any segments are drawn. For the same reason strings with more than
+    /// one digit are drawn with [`draw_digits`] instead.
+    pub(crate) fn draw_in_text<C, D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        text: Rectangle,
+        index: usize,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        self.draw_glow(style, text, index, target)?;
+        self.draw_segments(style, text, index, target)
+    }
+
+    /// Draws the glow around the active segments.
+    pub(crate) fn draw_glow<C, D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        text: Rectangle,
+        index: usize,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        let cell = Rectangle::new(self.position, style.digit_size);
+
+        if let (Some(glow), Some(fill)) = (
+            style.glow,
+            style.state_fill(true, cell, text, index, self.brightness),
+        ) {
+            let rects = segment_rects(self.position, style);
+            let active_segments = rects
+                .iter()
+                .filter(|(segment, _)| self.segments.contains(*segment))
+                .map(|(_, rect)| Segment::with_reduced_size(*rect, fill.color_at(rect.top_left)));
 
-        if let Some(color) = style.state_color(self.segments.contains(Segments::D)) {
-            Segment::with_reduced_size(rect.resized(vertical_size, AnchorPoint::BottomLeft), color)
-                .draw(target)?;
+            glow.draw(
+                cell,
+                active_segments,
+                &fill,
+                style.background_color,
+                style.blending,
+                target,
+            )?;
         }
 
-        if let Some(color) = style.state_color(self.segments.contains(Segments::E)) {
-            Segment::with_reduced_size(
-                rect.resized(horizontal_size_bottom, AnchorPoint::BottomLeft),
-                color,
-            )
-            .draw(target)?;
+        Ok(())
+    }
+
+    /// Draws the segments, including their drop shadows and bevels.
+    ///
+    /// Returns the position of the next digit.
+    pub(crate) fn draw_segments<C, D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        text: Rectangle,
+        index: usize,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        let cell = Rectangle::new(self.position, style.digit_size);
+        let rects = segment_rects(self.position, style);
+
+        let active_fill = style.state_fill(true, cell, text, index, self.brightness);
+        let inactive_fill = style.state_fill(false, cell, text, index, self.brightness);
+
+        if let Some(shadow) = style.drop_shadow {
+            for (_, rect) in rects
+                .iter()
+                .filter(|(segment, _)| self.segments.contains(*segment))
+            {
+                let color = style.dimmed_color(shadow.color, self.brightness);
+
+                Segment::with_reduced_size(rect.translate(shadow.offset), color)
+                    .with_rendering(style.segment_rendering)
+                    .draw(target)?;
+            }
         }
 
-        if let Some(color) = style.state_color(self.segments.contains(Segments::F)) {
-            Segment::with_reduced_size(
-                rect.resized(horizontal_size_top, AnchorPoint::TopLeft),
-                color,
-            )
-            .draw(target)?;
+        let bevel = style.bevel.map(|bevel| Bevel {
+            light_color: style.dimmed_color(bevel.light_color, self.brightness),
+            dark_color: style.dimmed_color(bevel.dark_color, self.brightness),
+            ..bevel
+        });
+
+        for (segment, rect) in rects {
+            let state = self.segments.contains(segment);
+            let fill = if state { active_fill } else { inactive_fill };
+
+            if let Some(fill) = fill {
+                Segment::with_reduced_size(rect, fill.color_at(rect.top_left))
+                    .with_fill(fill)
+                    .with_rendering(style.state_rendering(state))
+                    .with_bevel(bevel.filter(|_| state))
+                    .draw(target)?;
+            }
         }
 
-        if let Some(color) = style.state_color(self.segments.contains(Segments::G)) {
-            Segment::with_reduced_size(rect.resized(vertical_size, AnchorPoint::CenterLeft), color)
-                .draw(target)?;
+        Ok(self.position + style.advance(style.digit_size.width))
+    }
+}
+
+/// Draws a row of digits.
+///
+/// The glow of all digits is drawn before any segments, because it extends into the neighboring
+/// cells and would otherwise cover their segments. The backgrounds aren't drawn.
+///
+/// Returns the position after the last digit.
+pub(crate) fn draw_digits<C, D, I>(
+    digits: I,
+    position: Point,
+    style: &SevenSegmentStyle<C>,
+    text: Rectangle,
+    target: &mut D,
+) -> Result<Point, D::Error>
+where
+    C: PixelColor,
+    D: DrawTarget<Color = C>,
+    I: Iterator<Item = Segments> + Clone,
+{
+    let advance = style.advance(style.digit_size.width);
+
+    if style.glow.is_some() {
+        let mut digit_position = position;
+        for (index, segments) in digits.clone().enumerate() {
+            Digit::new(segments, digit_position).draw_glow(style, text, index, target)?;
+            digit_position += advance;
         }
+    }
 
-        Ok(self.position + style.digit_size.x_axis() + Size::new(style.digit_spacing, 0))
+    let mut position = position;
+    for (index, segments) in digits.enumerate() {
+        position = Digit::new(segments, position).draw_segments(style, text, index, target)?;
     }
+
+    Ok(position)
+}
+
+/// Returns the bounding boxes of all segments in a digit.
+pub(crate) fn segment_rects<C>(
+    position: Point,
+    style: &SevenSegmentStyle<C>,
+) -> [(Segments, Rectangle); 7] {
+    let rect = Rectangle::new(position, style.digit_size);
+
+    let vertical_size = Size::new(style.digit_size.width, style.segment_width);
+    let horizontal_size_top = Size::new(
+        style.segment_width,
+        (style.digit_size.height + style.segment_width) / 2,
+    );
+    let horizontal_size_bottom = Size::new(
+        style.segment_width,
+        (style.digit_size.height + style.segment_width).div_ceil(2),
+    );
+
+    [
+        (
+            Segments::A,
+            rect.resized(vertical_size, AnchorPoint::TopLeft),
+        ),
+        (
+            Segments::B,
+            rect.resized(horizontal_size_top, AnchorPoint::TopRight),
+        ),
+        (
+            Segments::C,
+            rect.resized(horizontal_size_bottom, AnchorPoint::BottomRight),
+        ),
+        (
+            Segments::D,
+            rect.resized(vertical_size, AnchorPoint::BottomLeft),
+        ),
+        (
+            Segments::E,
+            rect.resized(horizontal_size_bottom, AnchorPoint::BottomLeft),
+        ),
+        (
+            Segments::F,
+            rect.resized(horizontal_size_top, AnchorPoint::TopLeft),
+        ),
+        (
+            Segments::G,
+            rect.resized(vertical_size, AnchorPoint::CenterLeft),
+        ),
+    ]
 }
@@ -0,0 +1,261 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+    text::{Alignment, Baseline},
+};
+
+use crate::{glyph::Glyph, Segments, SevenSegmentStyle, UnsupportedCharacterPolicy};
+
+/// Fixed width digit field.
+///
+/// A digit field displays a string inside a fixed number of cells. The string is justified
+/// inside the field according to the alignment and unused cells are filled with blank digits,
+/// which are drawn using the inactive segment style. Strings which are longer than the field
+/// are truncated on the side opposite to the alignment, e.g. a right aligned field keeps the
+/// rightmost characters and a centered field drops characters from both ends.
+///
+/// The field doesn't interpret the text as a number and therefore has no
+/// [`LeadingZeros`](crate::LeadingZeros) mode. Use a [`NumberDisplay`](crate::NumberDisplay)
+/// to display numbers with leading zeros.
+///
+/// The field is intended to be used with a style with a fixed
+/// [`cell_width`](SevenSegmentStyle::cell_width). Without a cell width colons and decimal
+/// points are narrower than a blank cell and the position of the string inside the field
+/// depends on its characters.
+///
+/// Unsupported characters are drawn according to the
+/// [`unsupported_character`](SevenSegmentStyle::unsupported_character) policy of the style.
+/// Skipped characters still occupy a cell and are drawn as empty glyphs to keep the number of
+/// cells constant.
+///
+/// The position is the top left corner of the first cell.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{
+///     prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable, text::Alignment,
+/// };
+/// use eg_seven_segment::{DigitField, SevenSegmentStyleBuilder};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .digit_size(Size::new(12, 24))
+///     .cell_width(12)
+///     .segment_color(Rgb888::GREEN)
+///     .inactive_segment_color(Rgb888::new(0, 32, 0))
+///     .build();
+///
+/// // Draws "  1.5" with two blank cells on the left.
+/// DigitField::new("1.5", 5, Point::new(5, 5))
+///     .with_alignment(Alignment::Right)
+///     .draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DigitField<'a> {
+    /// The text.
+    pub text: &'a str,
+
+    /// The number of cells.
+    pub cells: usize,
+
+    /// The alignment of the text inside the field.
+    pub alignment: Alignment,
+
+    /// The position.
+    pub position: Point,
+}
+
+impl<'a> DigitField<'a> {
+    /// Creates a new left aligned digit field.
+    pub fn new(text: &'a str, cells: usize, position: Point) -> Self {
+        Self {
+            text,
+            cells,
+            alignment: Alignment::Left,
+            position,
+        }
+    }
+
+    /// Sets the alignment.
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+
+        self
+    }
+
+    /// Returns the bounding box of the field.
+    pub fn bounding_box<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> Rectangle {
+        style
+            .measure_glyphs(
+                self.glyphs(style.unsupported_character)
+                    .map(|(glyph, _)| glyph),
+                self.position,
+                Baseline::Top,
+            )
+            .bounding_box
+    }
+
+    /// Returns the glyphs of the text padded with blank cells.
+    fn glyphs(
+        &self,
+        policy: UnsupportedCharacterPolicy,
+    ) -> impl Iterator<Item = (Glyph, Segments)> + Clone + 'a {
+        let count = self.text.chars().count();
+        let len = count.min(self.cells);
+        let padding = self.cells - len;
+        let overflow = count - len;
+
+        let (left, skip) = match self.alignment {
+            Alignment::Left => (0, 0),
+            Alignment::Center => (padding / 2, overflow / 2),
+            Alignment::Right => (padding, overflow),
+        };
+        let blank = (Glyph::Digit(Segments::empty()), Segments::empty());
+
+        core::iter::repeat_n(blank, left)
+            .chain(self.text.chars().skip(skip).take(len).map(move |c| {
+                let glyph = policy.apply(Glyph::from(c)).unwrap_or(Glyph::Empty);
+
+                (glyph, Segments::empty())
+            }))
+            .chain(core::iter::repeat_n(blank, padding - left))
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for DigitField<'_> {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        style.draw_glyphs(
+            self.glyphs(style.unsupported_character),
+            self.position,
+            Baseline::Top,
+            target,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_glyphs, style};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    fn assert_field(field: DigitField, expected: &str) {
+        assert_glyphs(field.glyphs(UnsupportedCharacterPolicy::Blank), expected);
+    }
+
+    #[test]
+    fn alignment() {
+        let field = DigitField::new("1:2", 6, Point::zero());
+
+        assert_field(field, "1:2   ");
+        assert_field(field.with_alignment(Alignment::Center), " 1:2  ");
+        assert_field(field.with_alignment(Alignment::Right), "   1:2");
+    }
+
+    #[test]
+    fn truncate() {
+        let field = DigitField::new("12345", 3, Point::zero());
+
+        assert_field(field, "123");
+        assert_field(field.with_alignment(Alignment::Center), "234");
+        assert_field(field.with_alignment(Alignment::Right), "345");
+        assert_field(DigitField::new("", 2, Point::zero()), "  ");
+    }
+
+    #[test]
+    fn unsupported_characters() {
+        let field = DigitField::new("1%2", 4, Point::zero());
+
+        assert_glyphs(
+            field.glyphs(UnsupportedCharacterPolicy::Replace(Segments::G)),
+            "1-2 ",
+        );
+        assert!(field
+            .glyphs(UnsupportedCharacterPolicy::Skip)
+            .map(|(glyph, _)| glyph)
+            .eq([
+                Glyph::from('1'),
+                Glyph::Empty,
+                Glyph::from('2'),
+                Glyph::Digit(Segments::empty()),
+            ]));
+    }
+
+    #[test]
+    fn constant_width() {
+        let style = SevenSegmentStyle {
+            cell_width: Some(5),
+            ..style()
+        };
+
+        for text in ["12.3", "1:23", "...."] {
+            let field = DigitField::new(text, 5, Point::zero()).with_alignment(Alignment::Right);
+
+            assert_eq!(
+                field.bounding_box(&style),
+                Rectangle::new(Point::zero(), Size::new(29, 9)),
+                "{:?}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn background() {
+        let style = SevenSegmentStyle {
+            cell_width: Some(5),
+            background_color: Some(BinaryColor::Off),
+            ..style()
+        };
+
+        // The mock display panics if the background of a blank cell is drawn twice.
+        let mut display = MockDisplay::new();
+        DigitField::new("", 3, Point::zero())
+            .draw_styled(&style, &mut display)
+            .unwrap();
+
+        display.assert_pattern(&[".................."; 9]);
+    }
+
+    #[test]
+    fn draw() {
+        let style = SevenSegmentStyle {
+            cell_width: Some(5),
+            ..style()
+        };
+
+        let field = DigitField::new("1.", 4, Point::zero()).with_alignment(Alignment::Center);
+
+        let mut display = MockDisplay::new();
+        let next = field.draw_styled(&style, &mut display).unwrap();
+        assert_eq!(next, Point::new(24, 0));
+
+        display.assert_pattern(&[
+            "               ", //
+            "          #    ", //
+            "          #    ", //
+            "          #    ", //
+            "               ", //
+            "          #    ", //
+            "          #    ", //
+            "          #    ", //
+            "              #", //
+        ]);
+    }
+}
@@ -0,0 +1,59 @@
+use embedded_graphics::prelude::*;
+
+/// Drop shadow effect.
+///
+/// The drop shadow is drawn behind active segments and uses the same shape as the segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DropShadow<C> {
+    /// The offset between the segments and their shadows.
+    pub offset: Point,
+
+    /// The shadow color.
+    pub color: C,
+}
+
+impl<C> DropShadow<C> {
+    /// Creates a new drop shadow effect.
+    pub fn new(offset: Point, color: C) -> Self {
+        Self { offset, color }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Digit, Segments, SevenSegmentStyleBuilder};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb888};
+
+    #[test]
+    fn drop_shadow() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(7, 11))
+            .segment_width(1)
+            .segment_color(Rgb888::RED)
+            .inactive_segment_color(Rgb888::GREEN)
+            .drop_shadow(DropShadow::new(Point::new(1, 1), Rgb888::BLUE))
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        Digit::new(Segments::A | Segments::B | Segments::C, Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " RRRRR  ", //
+            "G BBBBR ", //
+            "G     RB", //
+            "G     RB", //
+            "G     RB", //
+            " GGGGG B", //
+            "G     R ", //
+            "G     RB", //
+            "G     RB", //
+            "G     RB", //
+            " GGGGG B", //
+        ]);
+    }
+}
@@ -0,0 +1,109 @@
+/// Ghost segments.
+///
+/// Unlit segments on real LCDs are still faintly visible. If ghost segments are enabled, the
+/// color of inactive segments is derived from the color of active segments by blending it with
+/// the [`background_color`](crate::SevenSegmentStyle::background_color) of the style. If the
+/// style has no background color, the active segment color is dimmed towards black instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ghost<C> {
+    /// The opacity of ghost segments.
+    ///
+    /// An opacity of `0` results in the background color and `255` in the active segment color.
+    pub opacity: u8,
+
+    /// The color the ghost segments are blended with.
+    ///
+    /// If this color is set, it overrides the background color of the style.
+    pub background_color: Option<C>,
+}
+
+impl<C> Ghost<C> {
+    /// Creates a new ghost segment effect.
+    pub fn new(opacity: u8) -> Self {
+        Self {
+            opacity,
+            background_color: None,
+        }
+    }
+
+    /// Sets the color the ghost segments are blended with.
+    ///
+    /// This is only required if the ghost segments should be blended with a different color
+    /// than the background color of the style, e.g. if the text is drawn on top of an image.
+    pub fn with_background_color(mut self, background_color: C) -> Self {
+        self.background_color = Some(background_color);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Digit, Segments, SevenSegmentStyleBuilder};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Gray4, prelude::*};
+
+    #[test]
+    fn ghost_segments() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .segment_width(1)
+            .segment_color(Gray4::new(15))
+            .inactive_segment_color(Gray4::new(1))
+            .ghost(Ghost::new(85))
+            .build();
+
+        let mut display = MockDisplay::new();
+        Digit::new(Segments::B | Segments::C, Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " 555 ", //
+            "5   F", //
+            "5   F", //
+            "5   F", //
+            " 555 ", //
+            "5   F", //
+            "5   F", //
+            "5   F", //
+            " 555 ", //
+        ]);
+    }
+
+    fn test_ghost_color(ghost: Ghost<Gray4>, background_color: Option<Gray4>) -> Option<Gray4> {
+        let mut style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .segment_width(1)
+            .segment_color(Gray4::new(15))
+            .ghost(ghost)
+            .build();
+        style.background_color = background_color;
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        Digit::new(Segments::B, Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.get_pixel(Point::new(0, 2))
+    }
+
+    #[test]
+    fn blend_target() {
+        assert_eq!(
+            test_ghost_color(Ghost::new(85), Some(Gray4::new(6))),
+            Some(Gray4::new(9))
+        );
+        assert_eq!(test_ghost_color(Ghost::new(85), None), Some(Gray4::new(5)));
+        assert_eq!(
+            test_ghost_color(
+                Ghost::new(85).with_background_color(Gray4::new(0)),
+                Some(Gray4::new(6))
+            ),
+            Some(Gray4::new(5))
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use crate::{interpolate::Blending, segment::Segment, segment_fill::ResolvedFill};
+
+/// Glow effect.
+///
+/// The glow effect is drawn around active segments before the segments themselves are drawn.
+/// With increasing distance to the nearest active segment the segment color is blended towards
+/// the [`background_color`](crate::SevenSegmentStyle::background_color) of the style, until the
+/// background color is reached at the glow radius. If the style has no background color, the
+/// segment color is dimmed towards black instead.
+///
+/// The glow extends beyond the digit bounding box. The glow of all characters in a string is
+/// drawn before their segments, which keeps the segments visible if the glow radius is larger
+/// than the digit spacing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Glow<C> {
+    /// The glow radius in pixels.
+    pub radius: u32,
+
+    /// The color the glow is blended with.
+    ///
+    /// If this color is set, it overrides the background color of the style.
+    pub background_color: Option<C>,
+
+    /// The glow falloff.
+    pub falloff: GlowFalloff,
+}
+
+impl<C> Glow<C> {
+    /// Creates a new glow effect with linear falloff.
+    pub fn new(radius: u32) -> Self {
+        Self {
+            radius,
+            background_color: None,
+            falloff: GlowFalloff::Linear,
+        }
+    }
+
+    /// Sets the color the glow is blended with.
+    ///
+    /// This is only required if the glow should be blended with a different color than the
+    /// background color of the style, e.g. if the text is drawn on top of an image.
+    pub fn with_background_color(mut self, background_color: C) -> Self {
+        self.background_color = Some(background_color);
+
+        self
+    }
+
+    /// Sets the glow falloff.
+    pub fn with_falloff(mut self, falloff: GlowFalloff) -> Self {
+        self.falloff = falloff;
+
+        self
+    }
+}
+
+impl<C: PixelColor> Glow<C> {
+    /// Draws the glow around the given segments.
+    ///
+    /// `background_color` is the background color of the style, which is used if the glow
+    /// doesn't override it.
+    pub(crate) fn draw<D, I>(
+        &self,
+        bounding_box: Rectangle,
+        segments: I,
+        fill: &ResolvedFill<C>,
+        background_color: Option<C>,
+        blending: Blending<C>,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        I: Iterator<Item = Segment<C>> + Clone,
+    {
+        let radius_2x = self.radius.saturating_mul(2);
+        if radius_2x == 0 || segments.clone().next().is_none() {
+            return Ok(());
+        }
+
+        let area = bounding_box.offset(i32::try_from(self.radius).unwrap_or(i32::MAX));
+        let background_color = self.background_color.or(background_color);
+
+        target.draw_iter(area.points().filter_map(|point| {
+            let distance_squared = segments
+                .clone()
+                .map(|segment| segment.distance_squared_2x(point * 2 + Point::new(1, 1)))
+                .min()?;
+
+            // Pixels inside active segments are skipped, because they are drawn anyway.
+            if distance_squared == 0 {
+                return None;
+            }
+
+            let distance = distance_squared.isqrt();
+            if distance >= radius_2x {
+                return None;
+            }
+
+            let amount = 255 * (radius_2x - distance) / radius_2x;
+            let amount = match self.falloff {
+                GlowFalloff::Linear => amount,
+                GlowFalloff::Quadratic => amount * amount / 255,
+            };
+
+            let color = fill.color_at(point);
+            let color = match background_color {
+                Some(background_color) => {
+                    blending.interpolate(background_color, color, amount as u8)
+                }
+                None => blending.dim(color, amount as u8),
+            };
+
+            Some(Pixel(point, color))
+        }))
+    }
+}
+
+/// Glow falloff.
+///
+/// The falloff defines how fast the glow intensity decreases with increasing distance to the
+/// segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum GlowFalloff {
+    /// The intensity decreases linearly.
+    #[default]
+    Linear,
+
+    /// The intensity decreases quadratically.
+    Quadratic,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Digit, Segments, SevenSegmentStyleBuilder};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::Gray4,
+        text::{Baseline, Text},
+    };
+
+    fn test_glow(glow: Glow<Gray4>, segments: Segments, expected_pattern: &[&str]) {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .segment_width(1)
+            .segment_color(Gray4::WHITE)
+            .glow(glow)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Digit::new(segments, Point::new(3, 3))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(expected_pattern);
+    }
+
+    #[test]
+    fn linear() {
+        test_glow(
+            Glow::new(3).with_background_color(Gray4::BLACK),
+            Segments::B | Segments::C,
+            &[
+                "           ", //
+                "      222  ", //
+                "     57775 ", //
+                "    27ACA72", //
+                "    27CFC72", //
+                "    27CFC72", //
+                "    27CFC72", //
+                "    27ACA72", //
+                "    27CFC72", //
+                "    27CFC72", //
+                "    27CFC72", //
+                "    27ACA72", //
+                "     57775 ", //
+                "      222  ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn quadratic() {
+        test_glow(
+            Glow::new(3)
+                .with_background_color(Gray4::BLACK)
+                .with_falloff(GlowFalloff::Quadratic),
+            Segments::B | Segments::C,
+            &[
+                "           ", //
+                "      000  ", //
+                "     24442 ", //
+                "    047A740", //
+                "    04AFA40", //
+                "    04AFA40", //
+                "    04AFA40", //
+                "    047A740", //
+                "    04AFA40", //
+                "    04AFA40", //
+                "    04AFA40", //
+                "    047A740", //
+                "     24442 ", //
+                "      000  ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn horizontal_segment() {
+        test_glow(
+            Glow::new(2).with_background_color(Gray4::new(2)),
+            Segments::A,
+            &[
+                "         ", //
+                "   55555 ", //
+                "  58CCC85", //
+                "  5CFFFC5", //
+                "  58CCC85", //
+                "   55555 ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn multiple_digits() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(Gray4::WHITE)
+            .glow(Glow::new(3))
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        Text::with_baseline("88", Point::new(3, 3), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        // The B and C segments of the first digit aren't covered by the glow of the second digit.
+        for y in (4..7).chain(8..11) {
+            assert_eq!(display.get_pixel(Point::new(7, y)), Some(Gray4::WHITE));
+        }
+    }
+
+    #[test]
+    fn style_background_color() {
+        let style = |glow| {
+            SevenSegmentStyleBuilder::new()
+                .digit_size(Size::new(5, 9))
+                .segment_width(1)
+                .segment_color(Gray4::WHITE)
+                .background_color(Gray4::new(2))
+                .glow(glow)
+                .build()
+        };
+
+        let draw = |style| {
+            let mut display = MockDisplay::new();
+            display.set_allow_overdraw(true);
+            Digit::new(Segments::A, Point::new(3, 3))
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+            display
+        };
+
+        let expected = draw(style(Glow::new(2).with_background_color(Gray4::new(2))));
+        draw(style(Glow::new(2))).assert_eq(&expected);
+    }
+
+    #[test]
+    fn set_on_built_style() {
+        let mut style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .segment_width(1)
+            .segment_color(Gray4::WHITE)
+            .build();
+        let expected = SevenSegmentStyleBuilder::from(&style)
+            .glow(Glow::new(3))
+            .build();
+        style.glow = Some(Glow::new(3));
+
+        let draw = |style| {
+            let mut display = MockDisplay::new();
+            Digit::new(Segments::B | Segments::C, Point::new(3, 3))
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+            display
+        };
+
+        draw(style).assert_eq(&draw(expected));
+    }
+
+    #[test]
+    fn zero_radius() {
+        test_glow(
+            Glow::new(0),
+            Segments::A,
+            &[
+                "       ", //
+                "       ", //
+                "       ", //
+                "    FFF", //
+            ],
+        );
+    }
+}
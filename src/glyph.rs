@@ -0,0 +1,41 @@
+use core::convert::TryFrom;
+
+use crate::Segments;
+
+/// Glyph.
+///
+/// Glyphs are the building blocks of the text rendered by a
+/// [`SevenSegmentStyle`](crate::SevenSegmentStyle).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Glyph {
+    /// Seven-segment digit.
+    Digit(Segments),
+
+    /// Colon.
+    Colon,
+
+    /// Decimal point.
+    DecimalPoint,
+
+    /// Empty cell with the width of a digit.
+    Empty,
+
+    /// Unsupported character.
+    ///
+    /// Unsupported characters are replaced by other glyphs when the
+    /// [`UnsupportedCharacterPolicy`](crate::UnsupportedCharacterPolicy) is applied, which
+    /// requires them to be distinguishable from intentionally [`Empty`](Self::Empty) cells,
+    /// e.g. to not skip the padding of a number. Glyphs which are drawn without applying the
+    /// policy are treated like empty cells.
+    Unsupported,
+}
+
+impl From<char> for Glyph {
+    fn from(c: char) -> Self {
+        match c {
+            ':' => Self::Colon,
+            '.' => Self::DecimalPoint,
+            _ => Segments::try_from(c).map_or(Self::Unsupported, Self::Digit),
+        }
+    }
+}
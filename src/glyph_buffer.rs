@@ -0,0 +1,304 @@
+use core::convert::TryFrom;
+
+use embedded_graphics::{prelude::*, primitives::Rectangle, text::Baseline};
+
+use crate::{glyph::Glyph, Segments, SevenSegmentStyle, SignPlacement};
+
+/// Padding of numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Padding {
+    /// Pad with zeros.
+    Zeros,
+
+    /// Pad with digits with all segments inactive.
+    Inactive,
+
+    /// Pad with empty cells, which only draw the background.
+    Empty,
+}
+
+/// Fixed capacity glyph buffer.
+///
+/// The glyph buffer is used by widgets to format values without allocation. Each glyph is
+/// paired with a set of hidden segments, which are drawn as inactive segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct GlyphBuffer<const N: usize> {
+    glyphs: [(Glyph, Segments); N],
+    len: usize,
+}
+
+impl<const N: usize> GlyphBuffer<N> {
+    /// Creates an empty glyph buffer.
+    pub fn new() -> Self {
+        Self {
+            glyphs: [(Glyph::Unsupported, Segments::empty()); N],
+            len: 0,
+        }
+    }
+
+    /// Appends a glyph.
+    ///
+    /// Glyphs which don't fit into the buffer are discarded.
+    pub fn push(&mut self, glyph: Glyph) {
+        self.push_hidden(glyph, Segments::empty());
+    }
+
+    /// Appends a glyph with hidden segments.
+    ///
+    /// Glyphs which don't fit into the buffer are discarded.
+    pub fn push_hidden(&mut self, glyph: Glyph, hidden: Segments) {
+        if let Some(entry) = self.glyphs.get_mut(self.len) {
+            *entry = (glyph, hidden);
+            self.len += 1;
+        }
+    }
+
+    /// Appends a number.
+    ///
+    /// Numbers with less than `width` digits are padded on the left. Numbers with more digits
+    /// are appended completely, use [`digit_count`] to check the width beforehand.
+    pub fn push_number(&mut self, value: u32, radix: u32, width: usize, padding: Padding) {
+        let digits = digit_count(value, radix);
+
+        self.push_padding(width.saturating_sub(digits), padding);
+
+        let mut divisor = radix.pow(digits as u32 - 1);
+        while divisor > 0 {
+            self.push(Glyph::Digit(digit_segments(value / divisor % radix)));
+            divisor /= radix;
+        }
+    }
+
+    /// Appends a signed decimal number.
+    ///
+    /// Negative numbers are prefixed by a minus sign and positive numbers by the optional plus
+    /// sign. The sign counts towards the `width` of the number. Zero padded numbers always have
+    /// the sign in the leftmost position.
+    pub fn push_signed(
+        &mut self,
+        value: i32,
+        width: usize,
+        padding: Padding,
+        placement: SignPlacement,
+        plus_sign: Option<Segments>,
+    ) {
+        self.push_sign_magnitude(
+            value < 0,
+            value.unsigned_abs(),
+            width,
+            padding,
+            placement,
+            plus_sign,
+        );
+    }
+
+    /// Appends a signed decimal number given as sign and magnitude.
+    ///
+    /// This works like [`push_signed`](Self::push_signed), but allows negative numbers with a
+    /// magnitude of zero, e.g. for the integer part of `-0.5`.
+    pub fn push_sign_magnitude(
+        &mut self,
+        negative: bool,
+        magnitude: u32,
+        width: usize,
+        padding: Padding,
+        placement: SignPlacement,
+        plus_sign: Option<Segments>,
+    ) {
+        let sign = match (negative, magnitude) {
+            (true, _) => Some(Segments::G),
+            (false, 0) => None,
+            (false, _) => plus_sign,
+        };
+        let len = digit_count(magnitude, 10) + usize::from(sign.is_some());
+        let leftmost = placement == SignPlacement::Leftmost || padding == Padding::Zeros;
+
+        if leftmost {
+            self.push_sign(sign);
+        }
+
+        self.push_padding(width.saturating_sub(len), padding);
+
+        if !leftmost {
+            self.push_sign(sign);
+        }
+
+        self.push_number(magnitude, 10, 0, Padding::Zeros);
+    }
+
+    fn push_sign(&mut self, sign: Option<Segments>) {
+        if let Some(segments) = sign {
+            self.push(Glyph::Digit(segments));
+        }
+    }
+
+    fn push_padding(&mut self, count: usize, padding: Padding) {
+        for _ in 0..count {
+            self.push(match padding {
+                Padding::Zeros => Glyph::Digit(digit_segments(0)),
+                Padding::Inactive => Glyph::Digit(Segments::empty()),
+                Padding::Empty => Glyph::Empty,
+            });
+        }
+    }
+
+    /// Returns the bounding box of the glyphs drawn at the given top left position.
+    pub fn bounding_box<C: PixelColor>(
+        &self,
+        position: Point,
+        style: &SevenSegmentStyle<C>,
+    ) -> Rectangle {
+        style
+            .measure_glyphs(self.iter().map(|(glyph, _)| glyph), position, Baseline::Top)
+            .bounding_box
+    }
+
+    /// Returns an iterator over the glyphs and their hidden segments.
+    pub fn iter(&self) -> impl Iterator<Item = (Glyph, Segments)> + Clone + '_ {
+        self.glyphs[..self.len].iter().copied()
+    }
+}
+
+/// Returns the number of digits of a number.
+pub(crate) fn digit_count(mut value: u32, radix: u32) -> usize {
+    let mut count = 1;
+
+    while value >= radix {
+        value /= radix;
+        count += 1;
+    }
+
+    count
+}
+
+/// Returns the number of glyphs of a signed decimal number, including the sign.
+pub(crate) fn signed_digit_count(value: i32, plus_sign: bool) -> usize {
+    let sign = value < 0 || (value > 0 && plus_sign);
+
+    digit_count(value.unsigned_abs(), 10) + usize::from(sign)
+}
+
+/// Returns the segments of a single digit.
+///
+/// Values up to 15 are supported and hexadecimal digits are displayed as `A b C d E F`. Larger
+/// values return an empty set of segments.
+pub(crate) fn digit_segments(value: u32) -> Segments {
+    char::from_digit(value, 16)
+        .and_then(|c| Segments::try_from(c.to_ascii_uppercase()).ok())
+        .unwrap_or(Segments::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::text;
+
+    #[test]
+    fn push_number() {
+        let mut buffer = GlyphBuffer::<16>::new();
+        buffer.push_number(42, 10, 4, Padding::Zeros);
+        buffer.push(Glyph::Colon);
+        buffer.push_number(7, 10, 3, Padding::Inactive);
+        buffer.push(Glyph::DecimalPoint);
+        buffer.push_number(0xbeef, 16, 2, Padding::Zeros);
+
+        assert!(buffer.iter().eq(text("0042:  7.bEEF")));
+    }
+
+    #[test]
+    fn push_zero() {
+        let mut buffer = GlyphBuffer::<4>::new();
+        buffer.push_number(0, 10, 0, Padding::Inactive);
+
+        assert!(buffer.iter().eq(text("0")));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut buffer = GlyphBuffer::<3>::new();
+        buffer.push_number(12, 10, 2, Padding::Zeros);
+        buffer.push_hidden(Glyph::Colon, Segments::all());
+        buffer.push_number(34, 10, 2, Padding::Zeros);
+
+        assert_eq!(buffer.iter().count(), 3);
+        assert!(buffer
+            .iter()
+            .eq(text("12").chain(core::iter::once((Glyph::Colon, Segments::all())))));
+    }
+
+    #[test]
+    fn hex_digits() {
+        let mut buffer = GlyphBuffer::<6>::new();
+        buffer.push_number(0xabcdef, 16, 0, Padding::Zeros);
+
+        assert!(buffer.iter().eq(text("AbCdEF")));
+    }
+
+    #[test]
+    fn push_signed() {
+        let plus = Segments::B | Segments::C | Segments::G;
+        let signed = |value, width, padding, placement, plus_sign| {
+            let mut buffer = GlyphBuffer::<8>::new();
+            buffer.push_signed(value, width, padding, placement, plus_sign);
+            buffer
+        };
+
+        let buffer = signed(-5, 4, Padding::Inactive, SignPlacement::Adjacent, None);
+        assert!(buffer.iter().eq(text("  -5")));
+
+        let buffer = signed(-5, 4, Padding::Inactive, SignPlacement::Leftmost, None);
+        assert!(buffer.iter().eq(text("-  5")));
+
+        let buffer = signed(-5, 4, Padding::Zeros, SignPlacement::Adjacent, None);
+        assert!(buffer.iter().eq(text("-005")));
+
+        let buffer = signed(42, 4, Padding::Inactive, SignPlacement::Adjacent, None);
+        assert!(buffer.iter().eq(text("  42")));
+
+        let buffer = signed(0, 2, Padding::Inactive, SignPlacement::Adjacent, Some(plus));
+        assert!(buffer.iter().eq(text(" 0")));
+
+        let buffer = signed(7, 3, Padding::Inactive, SignPlacement::Leftmost, Some(plus));
+        let expected = [
+            Glyph::Digit(plus),
+            Glyph::Digit(Segments::empty()),
+            Glyph::from('7'),
+        ];
+        assert!(buffer.iter().map(|(glyph, _)| glyph).eq(expected));
+
+        let buffer = signed(
+            i32::MIN,
+            0,
+            Padding::Inactive,
+            SignPlacement::Adjacent,
+            None,
+        );
+        assert!(buffer.iter().eq(text("-2147483648").take(8)));
+    }
+
+    #[test]
+    fn push_negative_zero() {
+        let mut buffer = GlyphBuffer::<4>::new();
+        buffer.push_sign_magnitude(true, 0, 3, Padding::Inactive, SignPlacement::Adjacent, None);
+
+        assert!(buffer.iter().eq(text(" -0")));
+    }
+
+    #[test]
+    fn signed_count() {
+        assert_eq!(signed_digit_count(0, true), 1);
+        assert_eq!(signed_digit_count(5, false), 1);
+        assert_eq!(signed_digit_count(5, true), 2);
+        assert_eq!(signed_digit_count(-1000, false), 5);
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(digit_count(0, 10), 1);
+        assert_eq!(digit_count(9, 10), 1);
+        assert_eq!(digit_count(10, 10), 2);
+        assert_eq!(digit_count(u32::MAX, 10), 10);
+        assert_eq!(digit_count(0xff, 16), 2);
+        assert_eq!(digit_count(0o777, 8), 3);
+    }
+}
@@ -0,0 +1,350 @@
+use core::convert::TryFrom;
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+    text::Baseline,
+};
+
+use crate::{
+    glyph::Glyph,
+    glyph_buffer::{GlyphBuffer, Padding},
+    LeadingZeros, Segments, SevenSegmentStyle,
+};
+
+/// Number base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum NumberBase {
+    /// Hexadecimal.
+    ///
+    /// The digits `A` to `F` are displayed as `A b C d E F`, to make `b` and `d`
+    /// distinguishable from `8` and `0`.
+    #[default]
+    Hexadecimal,
+
+    /// Octal.
+    Octal,
+
+    /// Binary.
+    Binary,
+}
+
+impl NumberBase {
+    /// Returns the radix.
+    fn radix(self) -> u32 {
+        match self {
+            NumberBase::Hexadecimal => 16,
+            NumberBase::Octal => 8,
+            NumberBase::Binary => 2,
+        }
+    }
+
+    /// Returns the prefix letter.
+    ///
+    /// The letters are chosen to have segment patterns which differ from the patterns of all
+    /// hexadecimal digits, which rules out `b` for binary.
+    fn prefix(self) -> char {
+        match self {
+            NumberBase::Hexadecimal => 'H',
+            NumberBase::Octal => 'o',
+            NumberBase::Binary => 't',
+        }
+    }
+}
+
+/// Register value display.
+///
+/// The hex display shows an unsigned integer in hexadecimal, octal or binary. If a leading zeros
+/// mode is set, the value is padded to the width of the integer type, e.g. a `u16` value is
+/// padded to four hexadecimal digits.
+///
+/// If the prefix is enabled, the value is preceded by a letter which indicates the number
+/// base: `H` for hexadecimal, `o` for octal and `t` for binary values. The segment patterns of
+/// the prefix letters differ from the patterns of all hexadecimal digits, which makes it possible
+/// to tell them apart from the value.
+///
+/// The position is the top left corner of the first digit.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{HexDisplay, LeadingZeros, SevenSegmentStyleBuilder};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// // Draws "H00bd".
+/// HexDisplay::from_u16(0xbd, Point::new(5, 5))
+///     .with_leading_zeros(LeadingZeros::Show)
+///     .with_prefix(true)
+///     .draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexDisplay {
+    value: u32,
+    bits: u32,
+
+    /// The position.
+    pub position: Point,
+
+    /// The number base.
+    pub base: NumberBase,
+
+    /// The display mode of leading zeros.
+    ///
+    /// If set, the value is padded to the width of the integer type.
+    pub leading_zeros: Option<LeadingZeros>,
+
+    /// Show a prefix letter which indicates the number base.
+    pub prefix: bool,
+}
+
+/// The maximum number of glyphs in a hex display.
+const HEX_GLYPHS: usize = 33;
+
+impl HexDisplay {
+    /// Creates a new display for an `u8` value.
+    pub fn from_u8(value: u8, position: Point) -> Self {
+        Self::new(value.into(), u8::BITS, position)
+    }
+
+    /// Creates a new display for an `u16` value.
+    pub fn from_u16(value: u16, position: Point) -> Self {
+        Self::new(value.into(), u16::BITS, position)
+    }
+
+    /// Creates a new display for an `u32` value.
+    pub fn from_u32(value: u32, position: Point) -> Self {
+        Self::new(value, u32::BITS, position)
+    }
+
+    fn new(value: u32, bits: u32, position: Point) -> Self {
+        Self {
+            value,
+            bits,
+            position,
+            base: NumberBase::default(),
+            leading_zeros: None,
+            prefix: false,
+        }
+    }
+
+    /// Sets the number base.
+    pub fn with_base(mut self, base: NumberBase) -> Self {
+        self.base = base;
+
+        self
+    }
+
+    /// Pads the value to the width of the integer type with the given leading zeros mode.
+    pub fn with_leading_zeros(mut self, leading_zeros: LeadingZeros) -> Self {
+        self.leading_zeros = Some(leading_zeros);
+
+        self
+    }
+
+    /// Enables or disables the prefix.
+    pub fn with_prefix(mut self, prefix: bool) -> Self {
+        self.prefix = prefix;
+
+        self
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Returns the bounding box of the display.
+    pub fn bounding_box<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> Rectangle {
+        self.glyphs().bounding_box(self.position, style)
+    }
+
+    /// Formats the value.
+    fn glyphs(&self) -> GlyphBuffer<HEX_GLYPHS> {
+        let mut glyphs = GlyphBuffer::new();
+
+        if self.prefix {
+            glyphs.push(Glyph::Digit(
+                Segments::try_from(self.base.prefix()).unwrap(),
+            ));
+        }
+
+        let (width, padding) = match self.leading_zeros {
+            Some(leading_zeros) => {
+                let bits_per_digit = self.base.radix().trailing_zeros();
+                let width = self.bits.div_ceil(bits_per_digit) as usize;
+
+                (width, leading_zeros.padding())
+            }
+            None => (0, Padding::Zeros),
+        };
+
+        glyphs.push_number(self.value, self.base.radix(), width, padding);
+
+        glyphs
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for HexDisplay {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        style.draw_glyphs(self.glyphs().iter(), self.position, Baseline::Top, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        glyph_buffer::digit_segments,
+        test_utils::{assert_glyphs, style},
+    };
+    use embedded_graphics::mock_display::MockDisplay;
+
+    fn assert_hex(hex: HexDisplay, expected: &str) {
+        assert_glyphs(hex.glyphs().iter(), expected);
+    }
+
+    #[test]
+    fn hexadecimal() {
+        assert_hex(HexDisplay::from_u8(0, Point::zero()), "0");
+        assert_hex(HexDisplay::from_u8(0xff, Point::zero()), "FF");
+        assert_hex(HexDisplay::from_u16(0xbd, Point::zero()), "bd");
+        assert_hex(HexDisplay::from_u32(0xdeadbeef, Point::zero()), "dEAdbEEF");
+        assert_hex(HexDisplay::from_u32(0x1234abc, Point::zero()), "1234AbC");
+    }
+
+    #[test]
+    fn leading_zeros() {
+        assert_hex(
+            HexDisplay::from_u8(0xa, Point::zero()).with_leading_zeros(LeadingZeros::Show),
+            "0A",
+        );
+        assert_hex(
+            HexDisplay::from_u16(0xc, Point::zero()).with_leading_zeros(LeadingZeros::Show),
+            "000C",
+        );
+        assert_hex(
+            HexDisplay::from_u32(0, Point::zero()).with_leading_zeros(LeadingZeros::Show),
+            "00000000",
+        );
+        assert_hex(
+            HexDisplay::from_u16(0xc, Point::zero()).with_leading_zeros(LeadingZeros::Inactive),
+            "   C",
+        );
+    }
+
+    #[test]
+    fn prefix() {
+        assert_hex(
+            HexDisplay::from_u8(0x42, Point::zero()).with_prefix(true),
+            "H42",
+        );
+        assert_hex(
+            HexDisplay::from_u8(8, Point::zero())
+                .with_base(NumberBase::Octal)
+                .with_prefix(true),
+            "o10",
+        );
+        assert_hex(
+            HexDisplay::from_u8(5, Point::zero())
+                .with_base(NumberBase::Binary)
+                .with_prefix(true),
+            "t101",
+        );
+    }
+
+    #[test]
+    fn prefix_is_not_a_digit() {
+        for base in [
+            NumberBase::Hexadecimal,
+            NumberBase::Octal,
+            NumberBase::Binary,
+        ] {
+            let prefix = Segments::try_from(base.prefix()).unwrap();
+            assert!((0..16).all(|digit| digit_segments(digit) != prefix));
+        }
+    }
+
+    #[test]
+    fn octal() {
+        let octal = |hex: HexDisplay| hex.with_base(NumberBase::Octal);
+
+        assert_hex(octal(HexDisplay::from_u8(0o17, Point::zero())), "17");
+        assert_hex(
+            octal(HexDisplay::from_u8(0o17, Point::zero())).with_leading_zeros(LeadingZeros::Show),
+            "017",
+        );
+        assert_hex(
+            octal(HexDisplay::from_u16(u16::MAX, Point::zero()))
+                .with_leading_zeros(LeadingZeros::Show),
+            "177777",
+        );
+        assert_hex(
+            octal(HexDisplay::from_u32(1, Point::zero())).with_leading_zeros(LeadingZeros::Show),
+            "00000000001",
+        );
+    }
+
+    #[test]
+    fn binary() {
+        let binary = |hex: HexDisplay| hex.with_base(NumberBase::Binary);
+
+        assert_hex(binary(HexDisplay::from_u8(0b1010, Point::zero())), "1010");
+        assert_hex(
+            binary(HexDisplay::from_u8(0b1010, Point::zero()))
+                .with_leading_zeros(LeadingZeros::Show),
+            "00001010",
+        );
+        assert_hex(
+            binary(HexDisplay::from_u32(u32::MAX, Point::zero()))
+                .with_prefix(true)
+                .with_leading_zeros(LeadingZeros::Show),
+            "t11111111111111111111111111111111",
+        );
+    }
+
+    #[test]
+    fn draw() {
+        let style = style();
+
+        let hex = HexDisplay::from_u8(0xbd, Point::zero());
+        assert_eq!(
+            hex.bounding_box(&style),
+            Rectangle::new(Point::zero(), Size::new(11, 9))
+        );
+
+        let mut display = MockDisplay::new();
+        hex.draw_styled(&style, &mut display).unwrap();
+
+        display.assert_pattern(&[
+            "           ", //
+            "#         #", //
+            "#         #", //
+            "#         #", //
+            " ###   ### ", //
+            "#   # #   #", //
+            "#   # #   #", //
+            "#   # #   #", //
+            " ###   ### ", //
+        ]);
+    }
+}
@@ -0,0 +1,315 @@
+use embedded_graphics::pixelcolor::{
+    Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, Gray2, Gray4, Gray8, GrayColor, Rgb555, Rgb565,
+    Rgb666, Rgb888, RgbColor,
+};
+use embedded_graphics::prelude::*;
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// Color interpolation.
+///
+/// Effects that blend between two colors, like [`Glow`](crate::Glow), require the color type to
+/// implement this trait. It is implemented for all color types that are included in
+/// `embedded-graphics` and can be implemented for custom color types.
+pub trait Interpolate: PixelColor {
+    /// Interpolates between two colors.
+    ///
+    /// An `amount` of `0` returns `self` and an `amount` of `255` returns `other`.
+    fn interpolate(self, other: Self, amount: u8) -> Self;
+
+    /// Reduces the brightness of a color.
+    ///
+    /// A `brightness` of `255` returns the unchanged color and a `brightness` of `0` returns
+    /// black.
+    ///
+    /// The default implementation returns the unchanged color, which means that the
+    /// [`brightness`](crate::SevenSegmentStyle::brightness) of a style has no effect for color
+    /// types which don't override this method.
+    fn dim(self, brightness: u8) -> Self {
+        let _ = brightness;
+
+        self
+    }
+}
+
+/// Color blending functions.
+///
+/// Styles are generic over all [`PixelColor`]s, but effects which blend colors need an
+/// [`Interpolate`] implementation. The builder stores the interpolation functions of the color
+/// type in the style, unless the style was created for a color type without an implementation.
+/// Without them blending falls back to picking the nearer of the two colors and dimming leaves
+/// colors unchanged.
+pub(crate) struct Blending<C> {
+    functions: Option<(InterpolateFn<C>, DimFn<C>)>,
+}
+
+type InterpolateFn<C> = fn(C, C, u8) -> C;
+type DimFn<C> = fn(C, u8) -> C;
+
+impl<C> Blending<C> {
+    /// Creates a fallback blending without interpolation.
+    pub const fn stepped() -> Self {
+        Self { functions: None }
+    }
+}
+
+impl<C: Interpolate> Blending<C> {
+    /// Creates a blending which uses the [`Interpolate`] implementation of the color type.
+    pub fn interpolated() -> Self {
+        Self {
+            functions: Some((C::interpolate, C::dim)),
+        }
+    }
+}
+
+impl<C: PixelColor> Blending<C> {
+    /// Interpolates between two colors.
+    pub fn interpolate(&self, start: C, end: C, amount: u8) -> C {
+        match self.functions {
+            Some((interpolate, _)) => interpolate(start, end, amount),
+            None if amount < 128 => start,
+            None => end,
+        }
+    }
+
+    /// Reduces the brightness of a color.
+    pub fn dim(&self, color: C, brightness: u8) -> C {
+        match self.functions {
+            Some((_, dim)) => dim(color, brightness),
+            None => color,
+        }
+    }
+}
+
+// The stored functions are always the `Interpolate` implementation of `C`, which means that two
+// blendings are equal if both or neither of them interpolate.
+
+impl<C> Clone for Blending<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Blending<C> {}
+
+impl<C> fmt::Debug for Blending<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blending")
+            .field("interpolated", &self.functions.is_some())
+            .finish()
+    }
+}
+
+impl<C> PartialEq for Blending<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.functions.is_some() == other.functions.is_some()
+    }
+}
+
+impl<C> Eq for Blending<C> {}
+
+impl<C> PartialOrd for Blending<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for Blending<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.functions.is_some().cmp(&other.functions.is_some())
+    }
+}
+
+impl<C> Hash for Blending<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.functions.is_some().hash(state);
+    }
+}
+
+/// Scales the progress of a transition to the range `0..=max`.
+///
+/// The progress is clamped to the range `0.0..=1.0` and the result is rounded to the nearest
+/// integer.
+pub(crate) fn scale_progress(progress: f32, max: u32) -> u32 {
+    let progress = if progress > 0.0 {
+        progress.min(1.0)
+    } else {
+        0.0
+    };
+
+    // `f32::round` isn't available in `no_std` builds.
+    (progress * max as f32 + 0.5) as u32
+}
+
+/// Combines two brightness values.
+pub(crate) fn combine_brightness(a: u8, b: u8) -> u8 {
+    ((u16::from(a) * u16::from(b) + 127) / 255) as u8
+}
+
+/// Interpolates between two channel values.
+fn interpolate_channel(start: u8, end: u8, amount: u8) -> u8 {
+    let amount = u16::from(amount);
+
+    ((u16::from(start) * (255 - amount) + u16::from(end) * amount + 127) / 255) as u8
+}
+
+macro_rules! impl_rgb_interpolate {
+    ($($type:ident),*) => {
+        $(
+            impl Interpolate for $type {
+                fn interpolate(self, other: Self, amount: u8) -> Self {
+                    Self::new(
+                        interpolate_channel(self.r(), other.r(), amount),
+                        interpolate_channel(self.g(), other.g(), amount),
+                        interpolate_channel(self.b(), other.b(), amount),
+                    )
+                }
+
+                fn dim(self, brightness: u8) -> Self {
+                    Self::BLACK.interpolate(self, brightness)
+                }
+            }
+        )*
+    };
+}
+
+impl_rgb_interpolate!(Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+
+macro_rules! impl_gray_interpolate {
+    ($($type:ident),*) => {
+        $(
+            impl Interpolate for $type {
+                fn interpolate(self, other: Self, amount: u8) -> Self {
+                    Self::new(interpolate_channel(self.luma(), other.luma(), amount))
+                }
+
+                fn dim(self, brightness: u8) -> Self {
+                    <Self as GrayColor>::BLACK.interpolate(self, brightness)
+                }
+            }
+        )*
+    };
+}
+
+impl_gray_interpolate!(Gray2, Gray4, Gray8);
+
+impl Interpolate for BinaryColor {
+    fn interpolate(self, other: Self, amount: u8) -> Self {
+        if amount < 128 {
+            self
+        } else {
+            other
+        }
+    }
+
+    fn dim(self, brightness: u8) -> Self {
+        BinaryColor::Off.interpolate(self, brightness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgb888() {
+        let start = Rgb888::new(0, 100, 255);
+        let end = Rgb888::new(255, 200, 0);
+
+        assert_eq!(start.interpolate(end, 0), start);
+        assert_eq!(start.interpolate(end, 255), end);
+        assert_eq!(start.interpolate(end, 128), Rgb888::new(128, 150, 127));
+    }
+
+    #[test]
+    fn rgb565() {
+        let start = Rgb565::new(0, 0, 31);
+        let end = Rgb565::new(31, 63, 0);
+
+        assert_eq!(start.interpolate(end, 0), start);
+        assert_eq!(start.interpolate(end, 255), end);
+        assert_eq!(start.interpolate(end, 128), Rgb565::new(16, 32, 15));
+    }
+
+    #[test]
+    fn gray8() {
+        let start = Gray8::new(10);
+        let end = Gray8::new(250);
+
+        assert_eq!(start.interpolate(end, 0), start);
+        assert_eq!(start.interpolate(end, 255), end);
+        assert_eq!(start.interpolate(end, 64), Gray8::new(70));
+    }
+
+    #[test]
+    fn dim() {
+        assert_eq!(Rgb888::new(255, 100, 1).dim(128), Rgb888::new(128, 50, 1));
+        assert_eq!(Rgb888::new(255, 100, 1).dim(255), Rgb888::new(255, 100, 1));
+        assert_eq!(Rgb888::new(255, 100, 1).dim(0), Rgb888::BLACK);
+
+        assert_eq!(Rgb565::new(31, 63, 1).dim(128), Rgb565::new(16, 32, 1));
+        assert_eq!(Rgb565::new(31, 63, 1).dim(64), Rgb565::new(8, 16, 0));
+
+        assert_eq!(Gray8::new(200).dim(51), Gray8::new(40));
+        assert_eq!(Gray8::new(200).dim(0), Gray8::BLACK);
+
+        assert_eq!(BinaryColor::On.dim(128), BinaryColor::On);
+        assert_eq!(BinaryColor::On.dim(127), BinaryColor::Off);
+    }
+
+    #[test]
+    fn stepped_blending() {
+        let blending = Blending::stepped();
+
+        assert_eq!(
+            blending.interpolate(Gray8::new(10), Gray8::new(250), 127),
+            Gray8::new(10)
+        );
+        assert_eq!(
+            blending.interpolate(Gray8::new(10), Gray8::new(250), 128),
+            Gray8::new(250)
+        );
+        assert_eq!(blending.dim(Gray8::new(200), 0), Gray8::new(200));
+
+        let blending = Blending::interpolated();
+        assert_eq!(
+            blending.interpolate(Gray8::new(10), Gray8::new(250), 64),
+            Gray8::new(70)
+        );
+        assert_eq!(blending.dim(Gray8::new(200), 0), Gray8::BLACK);
+    }
+
+    #[test]
+    fn progress() {
+        assert_eq!(scale_progress(0.0, 255), 0);
+        assert_eq!(scale_progress(0.5, 255), 128);
+        assert_eq!(scale_progress(1.0, 255), 255);
+        assert_eq!(scale_progress(-1.0, 255), 0);
+        assert_eq!(scale_progress(2.0, 10), 10);
+        assert_eq!(scale_progress(f32::NAN, 10), 0);
+    }
+
+    #[test]
+    fn combine() {
+        assert_eq!(combine_brightness(255, 255), 255);
+        assert_eq!(combine_brightness(255, 100), 100);
+        assert_eq!(combine_brightness(128, 128), 64);
+        assert_eq!(combine_brightness(0, 255), 0);
+    }
+
+    #[test]
+    fn binary_color() {
+        assert_eq!(
+            BinaryColor::Off.interpolate(BinaryColor::On, 127),
+            BinaryColor::Off
+        );
+        assert_eq!(
+            BinaryColor::Off.interpolate(BinaryColor::On, 128),
+            BinaryColor::On
+        );
+    }
+}
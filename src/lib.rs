@@ -90,13 +90,65 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 #![deny(rustdoc::private_intra_doc_links)]
 
+mod bevel;
+mod blink;
+mod clock_display;
+mod date_display;
 mod digit;
+mod digit_field;
+mod drop_shadow;
+mod ghost;
+mod glow;
+mod glyph;
+mod glyph_buffer;
+mod hex_display;
+mod interpolate;
+mod marquee;
+mod measurement_display;
+mod morphing_digit;
+mod number_display;
+mod rolling_digit;
 mod segment;
+mod segment_bar;
+mod segment_fill;
+mod segment_rendering;
 mod segments;
 mod seven_segment_style;
 mod seven_segment_style_builder;
+mod spinner;
+#[cfg(test)]
+mod test_utils;
+mod text_block;
+mod text_flow;
+mod timer_display;
+mod unsupported_character;
 
+pub use bevel::Bevel;
+pub use blink::{Blink, BlinkingText};
+pub use clock_display::{AmPmIndicator, ClockDisplay, HourFormat};
+pub use date_display::{DateDisplay, DateOrder, DateSeparator, YearDigits};
 pub use digit::Digit;
+pub use digit_field::DigitField;
+pub use drop_shadow::DropShadow;
+pub use ghost::Ghost;
+pub use glow::{Glow, GlowFalloff};
+pub use hex_display::{HexDisplay, NumberBase};
+pub use interpolate::Interpolate;
+pub use marquee::{Marquee, MarqueeMode, MarqueeSource};
+pub use measurement_display::MeasurementDisplay;
+pub use morphing_digit::{MorphMode, MorphingDigit};
+pub use number_display::{LeadingZeros, NumberDisplay, NumberOverflow, SignPlacement};
+pub use rolling_digit::{RollDirection, RollingDigit};
+pub use segment_bar::{BarFillOrder, SegmentBar};
+pub use segment_fill::{GradientDirection, Palette, SegmentFill};
+pub use segment_rendering::SegmentRendering;
 pub use segments::Segments;
 pub use seven_segment_style::SevenSegmentStyle;
 pub use seven_segment_style_builder::SevenSegmentStyleBuilder;
+pub use spinner::{Spinner, SpinnerPattern};
+pub use text_block::TextBlock;
+pub use text_flow::TextFlow;
+pub use timer_display::{CountdownDisplay, StopwatchDisplay, TimerOverflow};
+pub use unsupported_character::{
+    check_string, is_representable, UnsupportedCharacter, UnsupportedCharacterPolicy,
+};
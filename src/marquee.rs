@@ -0,0 +1,294 @@
+use core::convert::TryFrom;
+
+use embedded_graphics::{prelude::*, primitives::StyledDrawable, text::Baseline};
+
+use crate::{digit::draw_digits, glyph::Glyph, Segments, SevenSegmentStyle};
+
+/// Marquee content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MarqueeSource<'a> {
+    /// Text.
+    ///
+    /// Each character occupies one digit. Characters that can't be displayed as a digit,
+    /// including colons and decimal points, are shown as blank digits.
+    Text(&'a str),
+
+    /// Segments.
+    Segments(&'a [Segments]),
+}
+
+impl MarqueeSource<'_> {
+    /// Returns the number of digits.
+    fn len(&self) -> usize {
+        match self {
+            MarqueeSource::Text(text) => text.chars().count(),
+            MarqueeSource::Segments(segments) => segments.len(),
+        }
+    }
+
+    /// Returns the segments of the digit at the given index.
+    fn get(&self, index: usize) -> Option<Segments> {
+        match self {
+            MarqueeSource::Text(text) => text
+                .chars()
+                .nth(index)
+                .map(|c| Segments::try_from(c).unwrap_or(Segments::empty())),
+            MarqueeSource::Segments(segments) => segments.get(index).copied(),
+        }
+    }
+}
+
+/// Marquee scroll mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum MarqueeMode {
+    /// Wrap around.
+    ///
+    /// The content scrolls to the left, followed by a gap as wide as the window, and then
+    /// reenters the window from the right.
+    #[default]
+    Wrap,
+
+    /// Bounce.
+    ///
+    /// The content scrolls to the left until the last digit is visible and then scrolls back to
+    /// the right. Content that fits into the window doesn't scroll.
+    Bounce,
+}
+
+/// Scrolling text.
+///
+/// A marquee shows a window of a fixed number of digits of a longer content. Each call to
+/// [`step`](Self::step) scrolls the content by one digit.
+///
+/// The position is the top left corner of the leftmost digit.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{Marquee, MarqueeSource, SevenSegmentStyleBuilder};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// let mut marquee = Marquee::new(MarqueeSource::Text("HELLO"), 4, Point::new(5, 5));
+///
+/// marquee.draw_styled(&style, &mut display)?;
+/// marquee.step();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Marquee<'a> {
+    source: MarqueeSource<'a>,
+    window: usize,
+    position: Point,
+    mode: MarqueeMode,
+    offset: usize,
+    reverse: bool,
+}
+
+impl<'a> Marquee<'a> {
+    /// Creates a new marquee.
+    ///
+    /// `window` is the number of visible digits.
+    pub fn new(source: MarqueeSource<'a>, window: usize, position: Point) -> Self {
+        Self {
+            source,
+            window,
+            position,
+            mode: MarqueeMode::default(),
+            offset: 0,
+            reverse: false,
+        }
+    }
+
+    /// Sets the scroll mode.
+    pub fn with_mode(mut self, mode: MarqueeMode) -> Self {
+        self.mode = mode;
+
+        self
+    }
+
+    /// Scrolls the content by one digit.
+    pub fn step(&mut self) {
+        let len = self.source.len();
+
+        match self.mode {
+            MarqueeMode::Wrap => {
+                self.offset = (self.offset + 1) % (len + self.window).max(1);
+            }
+            MarqueeMode::Bounce => {
+                let max = len.saturating_sub(self.window);
+
+                if max == 0 {
+                    self.offset = 0;
+                } else if self.reverse {
+                    self.offset -= 1;
+                    self.reverse = self.offset > 0;
+                } else {
+                    self.offset += 1;
+                    self.reverse = self.offset >= max;
+                }
+            }
+        }
+    }
+
+    /// Scrolls back to the start of the content.
+    pub fn reset(&mut self) {
+        self.offset = 0;
+        self.reverse = false;
+    }
+
+    /// Returns the segments of the visible digits.
+    pub fn frame(&self) -> impl Iterator<Item = Segments> + Clone + '_ {
+        let len = self.source.len();
+
+        (0..self.window).map(move |i| {
+            let index = match self.mode {
+                MarqueeMode::Wrap => (self.offset + i) % (len + self.window),
+                MarqueeMode::Bounce => self.offset + i,
+            };
+
+            self.source.get(index).unwrap_or(Segments::empty())
+        })
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for Marquee<'_> {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let text_bounds = style
+            .measure_glyphs(
+                (0..self.window).map(|_| Glyph::Digit(Segments::empty())),
+                self.position,
+                Baseline::Top,
+            )
+            .bounding_box;
+
+        style.draw_backgrounds(
+            (0..self.window).map(|_| style.digit_size.width),
+            self.position,
+            target,
+        )?;
+
+        draw_digits(self.frame(), self.position, style, text_bounds, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SevenSegmentStyleBuilder;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    fn frames<const N: usize>(mut marquee: Marquee<'_>) -> [[char; 2]; N] {
+        let mut frames = [[' '; 2]; N];
+
+        for frame in frames.iter_mut() {
+            for (c, segments) in frame.iter_mut().zip(marquee.frame()) {
+                *c = match segments {
+                    s if s == Segments::try_from('1').unwrap() => '1',
+                    s if s == Segments::try_from('2').unwrap() => '2',
+                    s if s == Segments::try_from('3').unwrap() => '3',
+                    s if s.is_empty() => ' ',
+                    _ => '?',
+                };
+            }
+            marquee.step();
+        }
+
+        frames
+    }
+
+    #[test]
+    fn wrap() {
+        let marquee = Marquee::new(MarqueeSource::Text("123"), 2, Point::zero());
+
+        assert_eq!(
+            frames::<6>(marquee),
+            [
+                ['1', '2'],
+                ['2', '3'],
+                ['3', ' '],
+                [' ', ' '],
+                [' ', '1'],
+                ['1', '2'],
+            ]
+        );
+    }
+
+    #[test]
+    fn bounce() {
+        let marquee = Marquee::new(MarqueeSource::Text("123"), 2, Point::zero())
+            .with_mode(MarqueeMode::Bounce);
+
+        assert_eq!(
+            frames::<5>(marquee),
+            [['1', '2'], ['2', '3'], ['1', '2'], ['2', '3'], ['1', '2']]
+        );
+    }
+
+    #[test]
+    fn bounce_short_content() {
+        let marquee =
+            Marquee::new(MarqueeSource::Text("1"), 2, Point::zero()).with_mode(MarqueeMode::Bounce);
+
+        assert_eq!(frames::<3>(marquee), [['1', ' '], ['1', ' '], ['1', ' ']]);
+    }
+
+    #[test]
+    fn segments_source() {
+        let segments = [Segments::try_from('3').unwrap(), Segments::empty()];
+        let mut marquee = Marquee::new(MarqueeSource::Segments(&segments), 1, Point::zero());
+
+        assert_eq!(frames::<3>(marquee), [['3', ' '], [' ', ' '], [' ', ' ']]);
+
+        marquee.step();
+        marquee.reset();
+        assert_eq!(frames::<1>(marquee), [['3', ' ']]);
+    }
+
+    #[test]
+    fn draw() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(BinaryColor::On)
+            .build();
+
+        let mut marquee = Marquee::new(MarqueeSource::Text("123"), 2, Point::zero());
+        marquee.step();
+
+        let mut display = MockDisplay::new();
+        let next = marquee.draw_styled(&style, &mut display).unwrap();
+        assert_eq!(next, Point::new(12, 0));
+
+        display.assert_pattern(&[
+            " ###   ### ", //
+            "    #     #", //
+            "    #     #", //
+            "    #     #", //
+            " ###   ### ", //
+            "#         #", //
+            "#         #", //
+            "#         #", //
+            " ###   ### ", //
+        ]);
+    }
+}
@@ -0,0 +1,281 @@
+use core::convert::TryFrom;
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+    text::Baseline,
+};
+
+use crate::{
+    glyph::Glyph,
+    glyph_buffer::{GlyphBuffer, Padding},
+    unsupported_character::check_representable,
+    Segments, SevenSegmentStyle, SignPlacement, UnsupportedCharacter,
+};
+
+/// Measurement.
+///
+/// The measurement widget displays a fixed point value followed by a unit, e.g. `23.5°C`. The
+/// value is stored as an integer together with the number of decimal places, which means that
+/// `23.5` is represented by a value of `235` with one decimal place. Negative values are
+/// prefixed by a `-` sign.
+///
+/// A range, e.g. `18-24°C`, can be displayed by setting an end value, which uses the same
+/// number of decimal places as the start value.
+///
+/// The position is the top left corner of the first digit.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), eg_seven_segment::UnsupportedCharacter> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{MeasurementDisplay, SevenSegmentStyleBuilder};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// // Draws "23.5°C".
+/// MeasurementDisplay::new(235, 1, Point::new(5, 5))
+///     .with_unit("°C")?
+///     .draw_styled(&style, &mut display)
+///     .unwrap();
+///
+/// // Percent signs can't be displayed with seven segments.
+/// assert!(MeasurementDisplay::new(42, 0, Point::zero()).with_unit("%").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MeasurementDisplay<'a> {
+    unit: &'a str,
+
+    /// The value.
+    pub value: i32,
+
+    /// The number of decimal places.
+    ///
+    /// Values greater than 9 are treated as 9.
+    pub decimal_places: u8,
+
+    /// The end value of a range.
+    pub range_end: Option<i32>,
+
+    /// The position.
+    pub position: Point,
+}
+
+/// The maximum number of glyphs in a measurement, excluding the unit.
+const MEASUREMENT_GLYPHS: usize = 25;
+
+impl<'a> MeasurementDisplay<'a> {
+    /// Creates a new measurement without a unit.
+    pub fn new(value: i32, decimal_places: u8, position: Point) -> Self {
+        Self {
+            unit: "",
+            value,
+            decimal_places,
+            range_end: None,
+            position,
+        }
+    }
+
+    /// Sets the end value of a range.
+    pub fn with_range_end(mut self, range_end: i32) -> Self {
+        self.range_end = Some(range_end);
+
+        self
+    }
+
+    /// Sets the unit.
+    ///
+    /// Returns an error with the first character which can't be displayed, if the unit contains
+    /// unsupported characters.
+    pub fn with_unit(mut self, unit: &'a str) -> Result<Self, UnsupportedCharacter> {
+        check_representable(unit)?;
+
+        self.unit = unit;
+
+        Ok(self)
+    }
+
+    /// Returns the unit.
+    pub fn unit(&self) -> &'a str {
+        self.unit
+    }
+
+    /// Returns the bounding box of the measurement.
+    pub fn bounding_box<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> Rectangle {
+        let glyphs = self.glyphs();
+
+        style
+            .measure_glyphs(
+                glyphs
+                    .iter()
+                    .chain(self.unit_glyphs())
+                    .map(|(glyph, _)| glyph),
+                self.position,
+                Baseline::Top,
+            )
+            .bounding_box
+    }
+
+    /// Formats the value and the optional range end.
+    fn glyphs(&self) -> GlyphBuffer<MEASUREMENT_GLYPHS> {
+        let mut glyphs = GlyphBuffer::new();
+
+        self.push_value(&mut glyphs, self.value);
+
+        if let Some(range_end) = self.range_end {
+            glyphs.push(Glyph::Digit(Segments::try_from('-').unwrap()));
+            self.push_value(&mut glyphs, range_end);
+        }
+
+        glyphs
+    }
+
+    /// Returns the unit glyphs.
+    ///
+    /// The unsupported character policy isn't applied, because [`with_unit`](Self::with_unit)
+    /// rejects units with unsupported characters.
+    fn unit_glyphs(&self) -> impl Iterator<Item = (Glyph, Segments)> + Clone + 'a {
+        self.unit
+            .chars()
+            .map(|c| (Glyph::from(c), Segments::empty()))
+    }
+
+    fn push_value(&self, glyphs: &mut GlyphBuffer<MEASUREMENT_GLYPHS>, value: i32) {
+        let decimal_places = self.decimal_places.min(9);
+        let divisor = 10u32.pow(decimal_places.into());
+        let magnitude = value.unsigned_abs();
+
+        glyphs.push_sign_magnitude(
+            value < 0,
+            magnitude / divisor,
+            0,
+            Padding::Zeros,
+            SignPlacement::Adjacent,
+            None,
+        );
+
+        if decimal_places > 0 {
+            glyphs.push(Glyph::DecimalPoint);
+            glyphs.push_number(
+                magnitude % divisor,
+                10,
+                decimal_places.into(),
+                Padding::Zeros,
+            );
+        }
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for MeasurementDisplay<'_> {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let glyphs = self.glyphs();
+
+        style.draw_glyphs(
+            glyphs.iter().chain(self.unit_glyphs()),
+            self.position,
+            Baseline::Top,
+            target,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_glyphs, style};
+    use embedded_graphics::mock_display::MockDisplay;
+
+    fn assert_measurement(measurement: MeasurementDisplay, expected: &str) {
+        let glyphs = measurement.glyphs();
+
+        assert_glyphs(glyphs.iter().chain(measurement.unit_glyphs()), expected);
+    }
+
+    #[test]
+    fn values() {
+        let measurement =
+            |value, decimal_places| MeasurementDisplay::new(value, decimal_places, Point::zero());
+
+        assert_measurement(measurement(0, 0), "0");
+        assert_measurement(measurement(235, 1), "23.5");
+        assert_measurement(measurement(5, 2), "0.05");
+        assert_measurement(measurement(1200, 2), "12.00");
+        assert_measurement(measurement(-5, 1), "-0.5");
+        assert_measurement(measurement(-123, 0), "-123");
+        assert_measurement(measurement(i32::MIN, 0), "-2147483648");
+        assert_measurement(measurement(1, 12), "0.000000001");
+    }
+
+    #[test]
+    fn units() {
+        let measurement = MeasurementDisplay::new(235, 1, Point::zero());
+
+        assert_measurement(measurement.with_unit("°C").unwrap(), "23.5°C");
+        assert_measurement(measurement.with_unit("°F").unwrap(), "23.5°F");
+        assert_measurement(measurement.with_unit("H").unwrap(), "23.5H");
+        assert_eq!(measurement.with_unit("%"), Err(UnsupportedCharacter('%')));
+        assert_eq!(measurement.with_unit("km"), Err(UnsupportedCharacter('k')));
+        assert_eq!(measurement.with_unit("°C").unwrap().unit(), "°C");
+        assert_eq!(measurement.unit(), "");
+    }
+
+    #[test]
+    fn ranges() {
+        let measurement = MeasurementDisplay::new(18, 0, Point::zero())
+            .with_range_end(24)
+            .with_unit("°C")
+            .unwrap();
+
+        assert_measurement(measurement, "18-24°C");
+
+        let measurement = MeasurementDisplay::new(-55, 1, Point::zero()).with_range_end(-15);
+        assert_measurement(measurement, "-5.5--1.5");
+    }
+
+    #[test]
+    fn draw() {
+        let style = style();
+
+        let measurement = MeasurementDisplay::new(-15, 1, Point::zero())
+            .with_unit("°C")
+            .unwrap();
+
+        assert_eq!(
+            measurement.bounding_box(&style),
+            Rectangle::new(Point::zero(), Size::new(31, 9))
+        );
+
+        let mut display = MockDisplay::new();
+        measurement.draw_styled(&style, &mut display).unwrap();
+
+        display.assert_pattern(&[
+            "               ###   ###   ###", //
+            "          #   #     #   # #   ", //
+            "          #   #     #   # #   ", //
+            "          #   #     #   # #   ", //
+            " ###           ###   ###      ", //
+            "          #       #       #   ", //
+            "          #       #       #   ", //
+            "          #       #       #   ", //
+            "            #  ###         ###", //
+        ]);
+    }
+}
@@ -0,0 +1,454 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+};
+
+use crate::{
+    digit::segment_rects, interpolate::scale_progress, segment::Segment, Bevel, Digit, Segments,
+    SevenSegmentStyle,
+};
+
+/// Morph mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum MorphMode {
+    /// Fade segments in and out.
+    ///
+    /// Changing segments are blended between the active color and the inactive segment color.
+    /// If no inactive segment color is set the background color is used instead. Without both
+    /// colors the segments switch at half of the transition.
+    #[default]
+    Fade,
+
+    /// Grow and shrink segments.
+    ///
+    /// Segments turning on grow from their center and segments turning off shrink towards their
+    /// center.
+    Grow,
+}
+
+/// Segment level transition between two digits.
+///
+/// Segments which are active in both digits are drawn as active segments during the whole
+/// transition. Segments which turn on or off are morphed according to the [`MorphMode`].
+/// During the transition the glow isn't drawn and drop shadows are only drawn for fully visible
+/// segments. At the start and end of the transition the digit is drawn exactly like a
+/// [`Digit`], including all effects.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use core::convert::TryFrom;
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{MorphMode, MorphingDigit, Segments, SevenSegmentStyleBuilder};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_overdraw(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .inactive_segment_color(Rgb888::new(0, 32, 0))
+///     .build();
+///
+/// let from = Segments::try_from('4').unwrap();
+/// let to = Segments::try_from('5').unwrap();
+///
+/// MorphingDigit::new(from, to, 0.5, Point::new(5, 5))
+///     .with_mode(MorphMode::Grow)
+///     .draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MorphingDigit {
+    /// The old digit.
+    pub from: Segments,
+
+    /// The new digit.
+    pub to: Segments,
+
+    /// The progress of the transition.
+    ///
+    /// The progress ranges from `0.0`, which shows the old digit, to `1.0`, which shows the new
+    /// digit. Values outside this range are clamped.
+    pub progress: f32,
+
+    /// The position of the top left corner of the digit.
+    pub position: Point,
+
+    /// The morph mode.
+    pub mode: MorphMode,
+}
+
+impl MorphingDigit {
+    /// Creates a new morphing digit.
+    pub fn new(from: Segments, to: Segments, progress: f32, position: Point) -> Self {
+        Self {
+            from,
+            to,
+            progress,
+            position,
+            mode: MorphMode::default(),
+        }
+    }
+
+    /// Sets the morph mode.
+    pub fn with_mode(mut self, mode: MorphMode) -> Self {
+        self.mode = mode;
+
+        self
+    }
+
+    /// Returns the visibility of a segment in the range `0..=255`.
+    fn visibility(&self, segment: Segments) -> u8 {
+        match (self.from.contains(segment), self.to.contains(segment)) {
+            (true, true) => u8::MAX,
+            (false, false) => 0,
+            (true, false) => u8::MAX - self.amount(),
+            (false, true) => self.amount(),
+        }
+    }
+
+    /// Returns the progress scaled to the range `0..=255`.
+    fn amount(&self) -> u8 {
+        scale_progress(self.progress, u8::MAX.into()) as u8
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for MorphingDigit {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        match self.amount() {
+            0 => return Digit::new(self.from, self.position).draw_styled(style, target),
+            u8::MAX => return Digit::new(self.to, self.position).draw_styled(style, target),
+            _ => {}
+        }
+
+        let cell = Rectangle::new(self.position, style.digit_size);
+        let rects = segment_rects(self.position, style);
+
+        style.draw_background(self.position, style.digit_size.width, target)?;
+
+        let active_fill = style.state_fill(true, cell, cell, 0, u8::MAX);
+        let inactive_fill = style.state_fill(false, cell, cell, 0, u8::MAX);
+
+        if let Some(shadow) = style.drop_shadow {
+            let color = style.dimmed_color(shadow.color, u8::MAX);
+
+            for (_, rect) in rects
+                .iter()
+                .filter(|(segment, _)| self.visibility(*segment) == u8::MAX)
+            {
+                Segment::with_reduced_size(rect.translate(shadow.offset), color)
+                    .with_rendering(style.segment_rendering)
+                    .draw(target)?;
+            }
+        }
+
+        let bevel = style.bevel.map(|bevel| Bevel {
+            light_color: style.dimmed_color(bevel.light_color, u8::MAX),
+            dark_color: style.dimmed_color(bevel.dark_color, u8::MAX),
+            ..bevel
+        });
+
+        for (segment, rect) in rects {
+            let visibility = self.visibility(segment);
+
+            let off_color = inactive_fill
+                .map(|fill| fill.color_at(rect.top_left))
+                .or(style.background_color);
+
+            let (inactive, active) = match self.mode {
+                MorphMode::Fade if visibility != u8::MAX && visibility != 0 => match off_color {
+                    Some(off_color) => (
+                        None,
+                        active_fill.map(|fill| {
+                            fill.map_colors(|color| {
+                                style.blending.interpolate(off_color, color, visibility)
+                            })
+                        }),
+                    ),
+                    None if visibility >= 128 => (None, active_fill),
+                    None => (inactive_fill, None),
+                },
+                _ if visibility == u8::MAX => (None, active_fill),
+                _ => (inactive_fill, active_fill.filter(|_| visibility > 0)),
+            };
+
+            if let Some(fill) = inactive {
+                Segment::with_reduced_size(rect, fill.color_at(rect.top_left))
+                    .with_fill(fill)
+                    .with_rendering(style.inactive_segment_rendering)
+                    .draw(target)?;
+            }
+
+            if let Some(fill) = active {
+                Segment::with_reduced_size(rect, fill.color_at(rect.top_left))
+                    .scaled(if self.mode == MorphMode::Grow {
+                        visibility
+                    } else {
+                        u8::MAX
+                    })
+                    .with_fill(fill)
+                    .with_rendering(style.segment_rendering)
+                    .with_bevel(bevel)
+                    .draw(target)?;
+            }
+        }
+
+        Ok(self.position + style.advance(style.digit_size.width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DropShadow, SevenSegmentStyleBuilder};
+    use core::convert::TryFrom;
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::{Gray4, Gray8},
+    };
+
+    fn style() -> SevenSegmentStyle<Gray4> {
+        SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(7, 11))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(Gray4::new(15))
+            .inactive_segment_color(Gray4::new(0))
+            .build()
+    }
+
+    fn draw(digit: MorphingDigit, style: SevenSegmentStyle<Gray4>, expected_pattern: &[&str]) {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        digit.draw_styled(&style, &mut display).unwrap();
+
+        display.assert_pattern(expected_pattern);
+    }
+
+    #[test]
+    fn fade() {
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('7').unwrap() | Segments::E;
+
+        draw(
+            MorphingDigit::new(from, to, 0.4, Point::zero()),
+            style(),
+            &[
+                " 66666 ", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                " 00000 ", //
+                "6     F", //
+                "6     F", //
+                "6     F", //
+                "6     F", //
+                " 00000 ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn fade_out() {
+        let from = Segments::try_from('7').unwrap();
+        let to = Segments::try_from('1').unwrap();
+
+        draw(
+            MorphingDigit::new(from, to, 0.8, Point::zero()),
+            style(),
+            &[
+                " 33333 ", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                " 00000 ", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                " 00000 ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn fade_without_inactive_color() {
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('7').unwrap();
+        let style = SevenSegmentStyleBuilder::from(&style())
+            .reset_inactive_segment_color()
+            .build();
+
+        draw(
+            MorphingDigit::new(from, to, 0.4, Point::zero()),
+            style,
+            &[
+                "       ", //
+                "      F", //
+                "      F", //
+                "      F", //
+                "      F", //
+                "       ", //
+                "      F", //
+                "      F", //
+                "      F", //
+                "      F", //
+            ],
+        );
+        draw(
+            MorphingDigit::new(from, to, 0.6, Point::zero()),
+            style,
+            &[
+                " FFFFF ", //
+                "      F", //
+                "      F", //
+                "      F", //
+                "      F", //
+                "       ", //
+                "      F", //
+                "      F", //
+                "      F", //
+                "      F", //
+            ],
+        );
+    }
+
+    fn effect_style() -> SevenSegmentStyle<Gray8> {
+        SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(7, 11))
+            .segment_width(3)
+            .segment_color(Gray8::new(200))
+            .inactive_segment_color(Gray8::new(20))
+            .bevel(Bevel::new(1, Gray8::new(240), Gray8::new(120)))
+            .drop_shadow(DropShadow::new(Point::new(1, 1), Gray8::new(80)))
+            .brightness(128)
+            .build()
+    }
+
+    fn draw_display<T>(drawable: T, style: &SevenSegmentStyle<Gray8>) -> MockDisplay<Gray8>
+    where
+        T: StyledDrawable<SevenSegmentStyle<Gray8>, Color = Gray8>,
+    {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        drawable.draw_styled(style, &mut display).unwrap();
+
+        display
+    }
+
+    #[test]
+    fn start_and_end_match_digit() {
+        let style = effect_style();
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('7').unwrap();
+
+        for mode in [MorphMode::Fade, MorphMode::Grow] {
+            let morph =
+                |progress| MorphingDigit::new(from, to, progress, Point::zero()).with_mode(mode);
+
+            draw_display(morph(0.0), &style)
+                .assert_eq(&draw_display(Digit::new(from, Point::zero()), &style));
+            draw_display(morph(1.0), &style)
+                .assert_eq(&draw_display(Digit::new(to, Point::zero()), &style));
+        }
+    }
+
+    #[test]
+    fn dimmed_effects_during_transition() {
+        let style = effect_style();
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('7').unwrap();
+
+        let morph = draw_display(MorphingDigit::new(from, to, 0.5, Point::zero()), &style);
+        let digit = draw_display(Digit::new(from, Point::zero()), &style);
+
+        // The B segment and its bevel and shadow are active in both digits.
+        for point in Rectangle::new(Point::new(4, 3), Size::new(4, 3)).points() {
+            assert_eq!(
+                morph.get_pixel(point),
+                digit.get_pixel(point),
+                "{:?}",
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn grow() {
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('7').unwrap() | Segments::E;
+
+        draw(
+            MorphingDigit::new(from, to, 0.4, Point::zero()).with_mode(MorphMode::Grow),
+            style(),
+            &[
+                " 0FF00 ", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                " 00000 ", //
+                "0     F", //
+                "F     F", //
+                "F     F", //
+                "0     F", //
+                " 00000 ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn grow_start_and_end() {
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('7').unwrap();
+
+        draw(
+            MorphingDigit::new(from, to, 0.0, Point::zero()).with_mode(MorphMode::Grow),
+            style(),
+            &[
+                " 00000 ", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                " 00000 ", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                " 00000 ", //
+            ],
+        );
+        draw(
+            MorphingDigit::new(from, to, 1.0, Point::zero()).with_mode(MorphMode::Grow),
+            style(),
+            &[
+                " FFFFF ", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                " 00000 ", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                "0     F", //
+                " 00000 ", //
+            ],
+        );
+    }
+}
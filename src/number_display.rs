@@ -0,0 +1,475 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+    text::Baseline,
+};
+
+use crate::{
+    glyph::Glyph,
+    glyph_buffer::{signed_digit_count, GlyphBuffer, Padding},
+    Segments, SevenSegmentStyle,
+};
+
+/// Position of the sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum SignPlacement {
+    /// The sign is placed directly before the first digit, e.g. `  -5`.
+    #[default]
+    Adjacent,
+
+    /// The sign is placed in the leftmost cell, e.g. `-  5`.
+    Leftmost,
+}
+
+/// Display mode of leading zeros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum LeadingZeros {
+    /// Leading zeros are displayed, e.g. `0042`.
+    ///
+    /// The sign of zero padded numbers is always placed in the leftmost cell, e.g. `-005`.
+    Show,
+
+    /// Leading zeros are suppressed and the cells are left empty.
+    ///
+    /// Only the background is drawn in empty cells, inactive segments aren't shown.
+    Suppress,
+
+    /// Leading zeros are displayed as digits with all segments inactive.
+    ///
+    /// The inactive segments are drawn in the inactive segment color or as ghost segments,
+    /// which makes the unused cells visible on displays which show unlit segments.
+    #[default]
+    Inactive,
+}
+
+impl LeadingZeros {
+    /// Returns the padding of numbers.
+    pub(crate) fn padding(self) -> Padding {
+        match self {
+            LeadingZeros::Show => Padding::Zeros,
+            LeadingZeros::Suppress => Padding::Empty,
+            LeadingZeros::Inactive => Padding::Inactive,
+        }
+    }
+}
+
+/// Number overflow behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum NumberOverflow {
+    /// Numbers which don't fit are replaced by the largest or smallest number which fits,
+    /// e.g. `-1000` is displayed as `-999` in four cells.
+    ///
+    /// If not even a single digit fits next to the sign, e.g. for a negative number in a single
+    /// cell, only the sign is displayed. Negative numbers are displayed as a dash and positive
+    /// numbers as the plus sign.
+    #[default]
+    Saturate,
+
+    /// Numbers which don't fit are replaced by a dash in every cell, e.g. `----`.
+    Dashes,
+}
+
+/// Signed number.
+///
+/// The number display shows a right aligned decimal number in a fixed number of cells. Negative
+/// numbers are prefixed by a minus sign and positive numbers can optionally be prefixed by a
+/// plus sign. Because seven segments can't display a `+` character, the segments which are used
+/// for the plus sign need to be specified.
+///
+/// The sign counts towards the number of cells. Numbers which don't fit into the cells are
+/// handled according to the overflow behavior. Unused cells on the left are filled according
+/// to the leading zeros mode.
+///
+/// The position is the top left corner of the first cell.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{NumberDisplay, Segments, SevenSegmentStyleBuilder, SignPlacement};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// // Draws "-  5".
+/// NumberDisplay::new(-5, 4, Point::new(5, 5))
+///     .with_sign_placement(SignPlacement::Leftmost)
+///     .draw_styled(&style, &mut display)?;
+///
+/// // Draws " +42" with a plus sign in the shape of a `t`.
+/// NumberDisplay::new(42, 4, Point::new(5, 40))
+///     .with_plus_sign(Segments::D | Segments::E | Segments::F | Segments::G)
+///     .draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NumberDisplay {
+    /// The value.
+    pub value: i32,
+
+    /// The number of cells.
+    ///
+    /// Values greater than 16 are treated as 16.
+    pub cells: usize,
+
+    /// The position.
+    pub position: Point,
+
+    /// The placement of the sign.
+    pub sign_placement: SignPlacement,
+
+    /// The segments of the plus sign.
+    ///
+    /// If set, positive numbers are prefixed by a digit with these segments.
+    pub plus_sign: Option<Segments>,
+
+    /// The overflow behavior.
+    pub overflow: NumberOverflow,
+
+    /// The display mode of leading zeros.
+    pub leading_zeros: LeadingZeros,
+}
+
+/// The maximum number of glyphs in a number.
+const NUMBER_GLYPHS: usize = 16;
+
+impl NumberDisplay {
+    /// Creates a new number.
+    pub fn new(value: i32, cells: usize, position: Point) -> Self {
+        Self {
+            value,
+            cells,
+            position,
+            sign_placement: SignPlacement::default(),
+            plus_sign: None,
+            overflow: NumberOverflow::default(),
+            leading_zeros: LeadingZeros::default(),
+        }
+    }
+
+    /// Sets the placement of the sign.
+    pub fn with_sign_placement(mut self, sign_placement: SignPlacement) -> Self {
+        self.sign_placement = sign_placement;
+
+        self
+    }
+
+    /// Sets the segments of the plus sign.
+    pub fn with_plus_sign(mut self, plus_sign: Segments) -> Self {
+        self.plus_sign = Some(plus_sign);
+
+        self
+    }
+
+    /// Sets the overflow behavior.
+    pub fn with_overflow(mut self, overflow: NumberOverflow) -> Self {
+        self.overflow = overflow;
+
+        self
+    }
+
+    /// Sets the display mode of leading zeros.
+    pub fn with_leading_zeros(mut self, leading_zeros: LeadingZeros) -> Self {
+        self.leading_zeros = leading_zeros;
+
+        self
+    }
+
+    /// Returns `true` if the value doesn't fit into the cells.
+    pub fn is_overflow(&self) -> bool {
+        signed_digit_count(self.value, self.plus_sign.is_some()) > self.cells()
+    }
+
+    /// Returns the bounding box of the number.
+    pub fn bounding_box<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> Rectangle {
+        self.glyphs().bounding_box(self.position, style)
+    }
+
+    /// Returns the number of cells, limited to the glyph buffer capacity.
+    fn cells(&self) -> usize {
+        self.cells.min(NUMBER_GLYPHS)
+    }
+
+    /// Returns the value clamped to the largest or smallest value which fits into the cells.
+    ///
+    /// Returns `None` if no digit fits next to the sign of the value, in which case only the
+    /// sign is displayed.
+    fn saturated(&self) -> Option<i32> {
+        let (max, min) = self.limits();
+        let value = self.value.clamp(min, max);
+
+        // Overflowing values are never zero, unless there is no room for a digit.
+        (value != 0).then_some(value)
+    }
+
+    /// Returns the largest and smallest values which fit into the cells.
+    fn limits(&self) -> (i32, i32) {
+        let limit = |digits: usize| {
+            10i64
+                .checked_pow(digits as u32)
+                .map_or(i64::MAX, |power| power - 1)
+        };
+
+        let positive_digits = self.cells() - usize::from(self.plus_sign.is_some());
+        let negative_digits = self.cells().saturating_sub(1);
+
+        let max = limit(positive_digits).min(i32::MAX.into()) as i32;
+        let min = (-limit(negative_digits)).max(i32::MIN.into()) as i32;
+
+        (max, min)
+    }
+
+    /// Formats the number.
+    fn glyphs(&self) -> GlyphBuffer<NUMBER_GLYPHS> {
+        let mut glyphs = GlyphBuffer::new();
+
+        if self.cells() == 0 {
+            return glyphs;
+        }
+
+        let value = match (self.is_overflow(), self.overflow) {
+            (false, _) => Some(self.value),
+            (true, NumberOverflow::Saturate) => self.saturated(),
+            (true, NumberOverflow::Dashes) => None,
+        };
+
+        let Some(value) = value else {
+            let segments = match self.plus_sign {
+                Some(plus_sign) if self.overflow == NumberOverflow::Saturate && self.value > 0 => {
+                    plus_sign
+                }
+                _ => Segments::G,
+            };
+
+            for _ in 0..self.cells() {
+                glyphs.push(Glyph::Digit(segments));
+            }
+
+            return glyphs;
+        };
+
+        glyphs.push_signed(
+            value,
+            self.cells(),
+            self.leading_zeros.padding(),
+            self.sign_placement,
+            self.plus_sign,
+        );
+
+        glyphs
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for NumberDisplay {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        style.draw_glyphs(self.glyphs().iter(), self.position, Baseline::Top, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_glyphs, style};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    const PLUS: Segments = Segments::B.union(Segments::C).union(Segments::G);
+
+    fn assert_number(number: NumberDisplay, expected: &str) {
+        assert_glyphs(number.glyphs().iter(), expected);
+    }
+
+    #[test]
+    fn sign_placement() {
+        let number = NumberDisplay::new(-5, 4, Point::zero());
+
+        assert_number(number, "  -5");
+        assert_number(number.with_sign_placement(SignPlacement::Leftmost), "-  5");
+        assert_number(NumberDisplay::new(5, 4, Point::zero()), "   5");
+        assert_number(NumberDisplay::new(0, 4, Point::zero()), "   0");
+    }
+
+    #[test]
+    fn plus_sign() {
+        let number = NumberDisplay::new(42, 4, Point::zero()).with_plus_sign(PLUS);
+
+        let glyphs = number.glyphs();
+        let expected = [
+            Glyph::Digit(Segments::empty()),
+            Glyph::Digit(PLUS),
+            Glyph::from('4'),
+            Glyph::from('2'),
+        ];
+        assert!(glyphs.iter().map(|(glyph, _)| glyph).eq(expected));
+
+        assert_number(
+            NumberDisplay::new(0, 2, Point::zero()).with_plus_sign(PLUS),
+            " 0",
+        );
+    }
+
+    #[test]
+    fn saturate() {
+        let number = |value| NumberDisplay::new(value, 4, Point::zero());
+
+        assert!(!number(-999).is_overflow());
+        assert!(number(-1000).is_overflow());
+        assert!(number(10000).is_overflow());
+
+        assert_number(number(-1000), "-999");
+        assert_number(number(12345), "9999");
+        assert_number(number(i32::MIN), "-999");
+        assert_number(number(i32::MAX), "9999");
+        assert_number(NumberDisplay::new(-5, 1, Point::zero()), "-");
+        assert_number(NumberDisplay::new(5, 1, Point::zero()), "5");
+        assert_number(NumberDisplay::new(42, 1, Point::zero()), "9");
+        assert_number(
+            NumberDisplay::new(0, 1, Point::zero()).with_plus_sign(PLUS),
+            "0",
+        );
+        assert_number(
+            NumberDisplay::new(i32::MIN, 16, Point::zero()),
+            "     -2147483648",
+        );
+
+        let glyphs = NumberDisplay::new(5, 1, Point::zero())
+            .with_plus_sign(PLUS)
+            .glyphs();
+        assert!(glyphs
+            .iter()
+            .map(|(glyph, _)| glyph)
+            .eq([Glyph::Digit(PLUS)]));
+
+        let glyphs = number(1000).with_plus_sign(PLUS).glyphs();
+        let expected = [
+            Glyph::Digit(PLUS),
+            Glyph::from('9'),
+            Glyph::from('9'),
+            Glyph::from('9'),
+        ];
+        assert!(glyphs.iter().map(|(glyph, _)| glyph).eq(expected));
+    }
+
+    #[test]
+    fn dashes() {
+        let number = |value| {
+            NumberDisplay::new(value, 4, Point::zero()).with_overflow(NumberOverflow::Dashes)
+        };
+
+        assert_number(number(-1000), "----");
+        assert_number(number(10000), "----");
+        assert_number(number(-999), "-999");
+        assert_number(
+            NumberDisplay::new(5, 1, Point::zero())
+                .with_overflow(NumberOverflow::Dashes)
+                .with_plus_sign(PLUS),
+            "-",
+        );
+        assert_number(NumberDisplay::new(1, 0, Point::zero()), "");
+    }
+
+    #[test]
+    fn leading_zeros() {
+        let number = NumberDisplay::new(-5, 4, Point::zero());
+
+        assert_number(number.with_leading_zeros(LeadingZeros::Show), "-005");
+        assert_number(
+            NumberDisplay::new(42, 4, Point::zero()).with_leading_zeros(LeadingZeros::Show),
+            "0042",
+        );
+        assert_number(number.with_leading_zeros(LeadingZeros::Inactive), "  -5");
+
+        let glyphs = number.with_leading_zeros(LeadingZeros::Suppress).glyphs();
+        let expected = [
+            Glyph::Empty,
+            Glyph::Empty,
+            Glyph::from('-'),
+            Glyph::from('5'),
+        ];
+        assert!(glyphs.iter().map(|(glyph, _)| glyph).eq(expected));
+    }
+
+    #[test]
+    fn draw_leading_zeros() {
+        let style = SevenSegmentStyle {
+            inactive_segment_color: Some(BinaryColor::Off),
+            ..style()
+        };
+
+        let number = NumberDisplay::new(1, 2, Point::zero());
+
+        let mut display = MockDisplay::new();
+        number
+            .with_leading_zeros(LeadingZeros::Inactive)
+            .draw_styled(&style, &mut display)
+            .unwrap();
+        display.assert_pattern(&[
+            " ...   ... ", //
+            ".   . .   #", //
+            ".   . .   #", //
+            ".   . .   #", //
+            " ...   ... ", //
+            ".   . .   #", //
+            ".   . .   #", //
+            ".   . .   #", //
+            " ...   ... ", //
+        ]);
+
+        let mut display = MockDisplay::new();
+        number
+            .with_leading_zeros(LeadingZeros::Suppress)
+            .draw_styled(&style, &mut display)
+            .unwrap();
+        display.assert_pattern(&[
+            "       ... ", //
+            "      .   #", //
+            "      .   #", //
+            "      .   #", //
+            "       ... ", //
+            "      .   #", //
+            "      .   #", //
+            "      .   #", //
+            "       ... ", //
+        ]);
+    }
+
+    #[test]
+    fn draw() {
+        let style = style();
+
+        let number = NumberDisplay::new(-7, 3, Point::zero());
+        assert_eq!(
+            number.bounding_box(&style),
+            Rectangle::new(Point::zero(), Size::new(17, 9))
+        );
+
+        let mut display = MockDisplay::new();
+        number.draw_styled(&style, &mut display).unwrap();
+
+        display.assert_pattern(&[
+            "             ### ", //
+            "                #", //
+            "                #", //
+            "                #", //
+            "       ###       ", //
+            "                #", //
+            "                #", //
+            "                #", //
+        ]);
+    }
+}
@@ -0,0 +1,261 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+};
+
+use crate::{interpolate::scale_progress, Digit, Segments, SevenSegmentStyle};
+
+/// Roll direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum RollDirection {
+    /// The old digit moves up and the new digit enters from below.
+    #[default]
+    Up,
+
+    /// The old digit moves down and the new digit enters from above.
+    Down,
+}
+
+/// Odometer style digit transition.
+///
+/// A rolling digit draws the transition between two digits by sliding them vertically through
+/// the digit cell, like the wheels of a mechanical counter. The drawing is clipped to the digit
+/// cell and the digits are separated by the digit spacing.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use core::convert::TryFrom;
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{RollingDigit, Segments, SevenSegmentStyleBuilder};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// let from = Segments::try_from('4').unwrap();
+/// let to = Segments::try_from('5').unwrap();
+///
+/// RollingDigit::new(from, to, 0.25, Point::new(5, 5)).draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct RollingDigit {
+    /// The old digit.
+    pub from: Segments,
+
+    /// The new digit.
+    pub to: Segments,
+
+    /// The progress of the transition.
+    ///
+    /// The progress ranges from `0.0`, which only shows the old digit, to `1.0`, which only shows
+    /// the new digit. Values outside this range are clamped.
+    pub progress: f32,
+
+    /// The position of the top left corner of the digit cell.
+    pub position: Point,
+
+    /// The roll direction.
+    pub direction: RollDirection,
+}
+
+impl RollingDigit {
+    /// Creates a new rolling digit.
+    pub fn new(from: Segments, to: Segments, progress: f32, position: Point) -> Self {
+        Self {
+            from,
+            to,
+            progress,
+            position,
+            direction: RollDirection::default(),
+        }
+    }
+
+    /// Sets the roll direction.
+    pub fn with_direction(mut self, direction: RollDirection) -> Self {
+        self.direction = direction;
+
+        self
+    }
+
+    /// Returns the vertical offset of the old digit.
+    fn offset<C>(&self, style: &SevenSegmentStyle<C>) -> i32 {
+        let pitch = style.digit_size.height + style.digit_spacing;
+        let offset = scale_progress(self.progress, pitch) as i32;
+
+        match self.direction {
+            RollDirection::Up => -offset,
+            RollDirection::Down => offset,
+        }
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for RollingDigit {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let cell = Rectangle::new(self.position, style.digit_size);
+
+        // The whole cell is cleared first, because the backgrounds of the two digits don't
+        // cover the spacing between them.
+        style.draw_background(self.position, style.digit_size.width, target)?;
+
+        let mut clipped = target.clipped(&cell);
+
+        let pitch = (style.digit_size.height + style.digit_spacing) as i32;
+        let offset = self.offset(style);
+        let to_offset = match self.direction {
+            RollDirection::Up => offset + pitch,
+            RollDirection::Down => offset - pitch,
+        };
+
+        for (segments, offset) in [(self.from, offset), (self.to, to_offset)] {
+            let position = self.position + Point::new(0, offset);
+            let bounds = Rectangle::new(position, style.digit_size);
+
+            Digit::new(segments, position).draw_in_text(style, bounds, 0, &mut clipped)?;
+        }
+
+        Ok(self.position + style.advance(style.digit_size.width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::style;
+    use core::convert::TryFrom;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    fn draw(digit: RollingDigit, expected_pattern: &[&str]) {
+        let mut display = MockDisplay::new();
+        digit.draw_styled(&style(), &mut display).unwrap();
+
+        display.assert_pattern(expected_pattern);
+    }
+
+    #[test]
+    fn start() {
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('2').unwrap();
+
+        draw(
+            RollingDigit::new(from, to, 0.0, Point::zero()),
+            &[
+                "     ", //
+                "    #", //
+                "    #", //
+                "    #", //
+                "     ", //
+                "    #", //
+                "    #", //
+                "    #", //
+            ],
+        );
+    }
+
+    #[test]
+    fn end() {
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('2').unwrap();
+
+        draw(
+            RollingDigit::new(from, to, 1.0, Point::zero()),
+            &[
+                " ### ", //
+                "    #", //
+                "    #", //
+                "    #", //
+                " ### ", //
+                "#    ", //
+                "#    ", //
+                "#    ", //
+                " ### ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn roll_up() {
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('2').unwrap();
+
+        draw(
+            RollingDigit::new(from, to, 0.5, Point::zero()),
+            &[
+                "    #", //
+                "    #", //
+                "    #", //
+                "     ", //
+                "     ", //
+                " ### ", //
+                "    #", //
+                "    #", //
+                "    #", //
+            ],
+        );
+    }
+
+    #[test]
+    fn roll_down() {
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('2').unwrap();
+
+        draw(
+            RollingDigit::new(from, to, 0.5, Point::zero()).with_direction(RollDirection::Down),
+            &[
+                "#    ", //
+                "#    ", //
+                "#    ", //
+                " ### ", //
+                "     ", //
+                "     ", //
+                "    #", //
+                "    #", //
+                "    #", //
+            ],
+        );
+    }
+
+    #[test]
+    fn background() {
+        let from = Segments::try_from('1').unwrap();
+        let to = Segments::try_from('2').unwrap();
+
+        let style = SevenSegmentStyle {
+            background_color: Some(BinaryColor::Off),
+            ..style()
+        };
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        RollingDigit::new(from, to, 0.5, Point::zero())
+            .draw_styled(&style, &mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "....#.", //
+            "....#.", //
+            "....#.", //
+            "......", //
+            "......", //
+            ".###..", //
+            "....#.", //
+            "....#.", //
+            "....#.", //
+        ]);
+    }
+}
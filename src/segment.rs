@@ -1,15 +1,31 @@
+use core::ops::Range;
+
 use embedded_graphics::{prelude::*, primitives::Rectangle};
 
+use crate::{segment_fill::ResolvedFill, Bevel, SegmentRendering};
+
 /// Segment drawable.
 pub struct Segment<C> {
     rect: Rectangle,
-    color: C,
+    horizontal: bool,
+    fill: ResolvedFill<C>,
+    rendering: SegmentRendering,
+    bevel: Option<Bevel<C>>,
 }
 
 impl<C> Segment<C> {
     /// Creates a new segment drawable.
-    pub fn new(rect: Rectangle, color: C) -> Self {
-        Self { rect, color }
+    ///
+    /// The orientation is passed in explicitly, because the shape of the rectangle of a short
+    /// segment doesn't necessarily match its orientation.
+    pub fn new(rect: Rectangle, horizontal: bool, color: C) -> Self {
+        Self {
+            rect,
+            horizontal,
+            fill: ResolvedFill::Solid(color),
+            rendering: SegmentRendering::Filled,
+            bevel: None,
+        }
     }
 
     /// Creates a new segment drawable with reduced size.
@@ -18,7 +34,8 @@ impl<C> Segment<C> {
     /// same top left corner don't overlap.
     pub fn with_reduced_size(mut rect: Rectangle, color: C) -> Self {
         // TODO: handle rects that are too small
-        if rect.size.width > rect.size.height {
+        let horizontal = rect.size.width > rect.size.height;
+        if horizontal {
             let size_offset = rect.size.height / 2 + 1;
             rect.top_left += Size::new(size_offset, 0);
             rect.size.width -= 2 * size_offset;
@@ -28,8 +45,220 @@ impl<C> Segment<C> {
             rect.size.height -= 2 * size_offset;
         }
 
-        Self::new(rect, color)
+        Self::new(rect, horizontal, color)
+    }
+
+    /// Scales the length of the segment around its center.
+    ///
+    /// An `amount` of `255` keeps the original length and `0` removes the segment. The
+    /// orientation of the segment is kept, even if the scaled segment is shorter than it is
+    /// wide.
+    pub fn scaled(mut self, amount: u8) -> Self {
+        let horizontal = self.horizontal;
+        let length = if horizontal {
+            self.rect.size.width
+        } else {
+            self.rect.size.height
+        };
+
+        let scaled = (u64::from(length) * u64::from(amount) + 127) / 255;
+        let scaled = scaled as u32;
+        let offset = ((length - scaled) / 2) as i32;
+
+        if horizontal {
+            self.rect.top_left.x += offset;
+            self.rect.size.width = scaled;
+        } else {
+            self.rect.top_left.y += offset;
+            self.rect.size.height = scaled;
+        }
+
+        self
+    }
+
+    /// Sets the fill.
+    pub fn with_fill(mut self, fill: ResolvedFill<C>) -> Self {
+        self.fill = fill;
+
+        self
+    }
+
+    /// Sets the rendering mode.
+    pub fn with_rendering(mut self, rendering: SegmentRendering) -> Self {
+        self.rendering = rendering;
+
+        self
+    }
+
+    /// Sets the bevel.
+    pub fn with_bevel(mut self, bevel: Option<Bevel<C>>) -> Self {
+        self.bevel = bevel;
+
+        self
+    }
+
+    /// Returns the squared distance between a point and the segment.
+    ///
+    /// The coordinates of the point and the returned distance are scaled by a factor of two to
+    /// be able to use pixel centers. Points inside the segment have a distance of `0`.
+    pub fn distance_squared_2x(&self, point: Point) -> u32 {
+        if self.rect.is_zero_sized() {
+            return u32::MAX;
+        }
+
+        let vertices = self.vertices_2x();
+        let edges = || {
+            vertices
+                .iter()
+                .zip(vertices.iter().cycle().skip(1))
+                .map(|(start, end)| (*start, *end - *start, point - *start))
+        };
+
+        let inside = edges().all(|(_, edge, offset)| {
+            i64::from(edge.x) * i64::from(offset.y) - i64::from(edge.y) * i64::from(offset.x) >= 0
+        });
+        if inside {
+            return 0;
+        }
+
+        edges()
+            .map(|(start, edge, offset)| {
+                let length_squared = dot(edge, edge);
+                let projection = dot(offset, edge);
+
+                if projection <= 0 {
+                    dot(offset, offset)
+                } else if projection >= length_squared {
+                    let offset = point - (start + edge);
+                    dot(offset, offset)
+                } else {
+                    let cross = i64::from(edge.x) * i64::from(offset.y)
+                        - i64::from(edge.y) * i64::from(offset.x);
+                    cross * cross / length_squared
+                }
+            })
+            .min()
+            .and_then(|distance| u32::try_from(distance).ok())
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Returns the vertices of the segment polygon in clockwise order.
+    ///
+    /// The coordinates are scaled by a factor of two.
+    fn vertices_2x(&self) -> [Point; 6] {
+        let top_left = self.rect.top_left * 2;
+        let bottom_right = top_left + self.rect.size * 2;
+        let center = top_left + self.rect.size;
+
+        if self.is_horizontal() {
+            let offset = self.rect.size.height as i32;
+
+            [
+                Point::new(top_left.x, center.y),
+                Point::new(top_left.x + offset, top_left.y),
+                Point::new(bottom_right.x - offset, top_left.y),
+                Point::new(bottom_right.x, center.y),
+                Point::new(bottom_right.x - offset, bottom_right.y),
+                Point::new(top_left.x + offset, bottom_right.y),
+            ]
+        } else {
+            let offset = self.rect.size.width as i32;
+
+            [
+                Point::new(center.x, top_left.y),
+                Point::new(bottom_right.x, top_left.y + offset),
+                Point::new(bottom_right.x, bottom_right.y - offset),
+                Point::new(center.x, bottom_right.y),
+                Point::new(top_left.x, bottom_right.y - offset),
+                Point::new(top_left.x, top_left.y + offset),
+            ]
+        }
+    }
+
+    /// Returns `true` if the segment is horizontal.
+    fn is_horizontal(&self) -> bool {
+        self.horizontal
+    }
+
+    /// Returns the segment bounding box shrunk by `width` on each side.
+    ///
+    /// `None` is returned if nothing of the segment remains.
+    fn inset(&self, width: u32) -> Option<Rectangle> {
+        let inner = self.rect.offset(-i32::try_from(width).unwrap_or(i32::MAX));
+
+        (!inner.is_zero_sized()).then_some(inner)
+    }
+}
+
+/// Returns the dot product of two vectors.
+fn dot(a: Point, b: Point) -> i64 {
+    i64::from(a.x) * i64::from(b.x) + i64::from(a.y) * i64::from(b.y)
+}
+
+/// Returns the rows (horizontal segments) or columns (vertical segments) of a segment.
+fn positions(rect: &Rectangle, horizontal: bool) -> Range<i32> {
+    if horizontal {
+        rect.rows()
+    } else {
+        rect.columns()
+    }
+}
+
+/// Returns the extent of a single row (horizontal segments) or column (vertical segments).
+///
+/// `None` is returned if `position` is outside the segment. The orientation is passed in
+/// explicitly to make sure that the outer and inner shapes of a segment are using the same
+/// orientation.
+fn span(rect: &Rectangle, position: i32, horizontal: bool) -> Option<Range<i32>> {
+    if !positions(rect, horizontal).contains(&position) {
+        return None;
+    }
+
+    let center_2x = rect.top_left * 2 + (rect.size - Size::new(1, 1));
+
+    Some(if horizontal {
+        let offset = (position * 2 - center_2x.y).abs() / 2;
+        rect.top_left.x + offset..rect.top_left.x + rect.size.width as i32 - offset
+    } else {
+        let offset = (position * 2 - center_2x.x).abs() / 2;
+        rect.top_left.y + offset..rect.top_left.y + rect.size.height as i32 - offset
+    })
+}
+
+/// Fills part of a row or column, leaving out the optional hole.
+fn fill_span<C, D>(
+    target: &mut D,
+    position: i32,
+    horizontal: bool,
+    span: Range<i32>,
+    hole: &Option<Range<i32>>,
+    fill: &ResolvedFill<C>,
+) -> Result<(), D::Error>
+where
+    C: PixelColor,
+    D: DrawTarget<Color = C>,
+{
+    let parts = match hole {
+        Some(hole) => [
+            span.start..span.end.min(hole.start),
+            span.start.max(hole.end)..span.end,
+        ],
+        None => [span, 0..0],
+    };
+
+    for part in parts.into_iter().filter(|part| !part.is_empty()) {
+        let length = (part.end - part.start) as u32;
+
+        let rect = if horizontal {
+            Rectangle::new(Point::new(part.start, position), Size::new(length, 1))
+        } else {
+            Rectangle::new(Point::new(position, part.start), Size::new(1, length))
+        };
+
+        fill.fill(&rect, target)?;
     }
+
+    Ok(())
 }
 
 impl<C: PixelColor> Drawable for Segment<C> {
@@ -44,31 +273,55 @@ impl<C: PixelColor> Drawable for Segment<C> {
             return Ok(());
         }
 
-        let center_2x = self.rect.top_left * 2 + (self.rect.size - Size::new(1, 1));
-
-        if self.rect.size.width > self.rect.size.height {
-            // Draw horizontal segment.
-            for y in self.rect.rows() {
-                let offset = (y * 2 - center_2x.y).abs() / 2;
-
-                let scanline = Rectangle::new(
-                    Point::new(self.rect.top_left.x + offset, y),
-                    Size::new(self.rect.size.width - offset as u32 * 2, 1),
-                );
-
-                target.fill_solid(&scanline, self.color)?;
-            }
+        let horizontal = self.is_horizontal();
+        let center_2x = if horizontal {
+            self.rect.top_left.y * 2 + self.rect.size.height as i32 - 1
         } else {
-            // Draw vertical segment.
-            for x in self.rect.columns() {
-                let offset = (x * 2 - center_2x.x).abs() / 2;
-
-                let scanline = Rectangle::new(
-                    Point::new(x, self.rect.top_left.y + offset),
-                    Size::new(1, self.rect.size.height - offset as u32 * 2),
-                );
-
-                target.fill_solid(&scanline, self.color)?;
+            self.rect.top_left.x * 2 + self.rect.size.width as i32 - 1
+        };
+
+        let hole_rect = match self.rendering {
+            SegmentRendering::Filled => None,
+            SegmentRendering::Outline { stroke_width } => self.inset(stroke_width),
+        };
+        let bevel_rect = self.bevel.and_then(|bevel| self.inset(bevel.width));
+
+        for position in positions(&self.rect, horizontal) {
+            let outer = span(&self.rect, position, horizontal).unwrap();
+            let hole = hole_rect.and_then(|rect| span(&rect, position, horizontal));
+
+            let Some(bevel) = self.bevel else {
+                fill_span(target, position, horizontal, outer, &hole, &self.fill)?;
+                continue;
+            };
+
+            if let Some(inner) = bevel_rect.and_then(|rect| span(&rect, position, horizontal)) {
+                let start = outer.start..inner.start;
+                let end = inner.end..outer.end;
+
+                let light = ResolvedFill::Solid(bevel.light_color);
+                let dark = ResolvedFill::Solid(bevel.dark_color);
+
+                fill_span(target, position, horizontal, start, &hole, &light)?;
+                fill_span(target, position, horizontal, inner, &hole, &self.fill)?;
+                fill_span(target, position, horizontal, end, &hole, &dark)?;
+            } else {
+                // Rows or columns which are completely inside the bevel use the light color in
+                // the top or left half of the segment and the dark color in the other half.
+                let color = if position * 2 < center_2x {
+                    bevel.light_color
+                } else {
+                    bevel.dark_color
+                };
+
+                fill_span(
+                    target,
+                    position,
+                    horizontal,
+                    outer,
+                    &hole,
+                    &ResolvedFill::Solid(color),
+                )?;
             }
         }
 
@@ -79,15 +332,40 @@ impl<C: PixelColor> Drawable for Segment<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Rgb888},
+    };
+
+    fn segment<C>(rect: Rectangle, color: C) -> Segment<C> {
+        Segment::new(rect, rect.size.width > rect.size.height, color)
+    }
 
     fn test_segment(rect: Rectangle, expected_pattern: &[&str]) {
         let mut display = MockDisplay::new();
-        Segment::new(rect, BinaryColor::On)
+        segment(rect, BinaryColor::On).draw(&mut display).unwrap();
+
+        display.assert_pattern(expected_pattern);
+    }
+
+    fn test_bevel(rect: Rectangle, width: u32, expected_pattern: &[&str]) {
+        let mut display = MockDisplay::new();
+        segment(rect, Rgb888::RED)
+            .with_bevel(Some(Bevel::new(width, Rgb888::WHITE, Rgb888::BLACK)))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(expected_pattern);
+    }
+
+    fn test_outline(rect: Rectangle, stroke_width: u32, expected_pattern: &[&str]) {
+        let mut display = MockDisplay::new();
+        segment(rect, BinaryColor::On)
+            .with_rendering(SegmentRendering::Outline { stroke_width })
             .draw(&mut display)
             .unwrap();
 
-        display.assert_pattern(&expected_pattern);
+        display.assert_pattern(expected_pattern);
     }
 
     #[test]
@@ -333,4 +611,153 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn outline_horizontal_5px() {
+        test_outline(
+            Rectangle::new(Point::zero(), Size::new(10, 5)),
+            1,
+            &[
+                "  ######  ", //
+                " #      # ", //
+                "#        #", //
+                " #      # ", //
+                "  ######  ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn outline_horizontal_7px_2px_stroke() {
+        test_outline(
+            Rectangle::new(Point::zero(), Size::new(12, 7)),
+            2,
+            &[
+                "   ######   ", //
+                "  ########  ", //
+                " ##      ## ", //
+                "##        ##", //
+                " ##      ## ", //
+                "  ########  ", //
+                "   ######   ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn outline_vertical_5px() {
+        test_outline(
+            Rectangle::new(Point::zero(), Size::new(5, 10)),
+            1,
+            &[
+                "  #  ", //
+                " # # ", //
+                "#   #", //
+                "#   #", //
+                "#   #", //
+                "#   #", //
+                "#   #", //
+                "#   #", //
+                " # # ", //
+                "  #  ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn outline_wider_than_segment() {
+        test_outline(
+            Rectangle::new(Point::zero(), Size::new(10, 3)),
+            2,
+            &[
+                " ######## ", //
+                "##########", //
+                " ######## ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bevel_horizontal() {
+        test_bevel(
+            Rectangle::new(Point::zero(), Size::new(10, 5)),
+            1,
+            &[
+                "  WWWWWW  ", //
+                " WRRRRRRK ", //
+                "WRRRRRRRRK", //
+                " WRRRRRRK ", //
+                "  KKKKKK  ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bevel_vertical() {
+        test_bevel(
+            Rectangle::new(Point::zero(), Size::new(5, 10)),
+            1,
+            &[
+                "  W  ", //
+                " WRW ", //
+                "WRRRK", //
+                "WRRRK", //
+                "WRRRK", //
+                "WRRRK", //
+                "WRRRK", //
+                "WRRRK", //
+                " KRK ", //
+                "  K  ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bevel_wider_than_segment() {
+        test_bevel(
+            Rectangle::new(Point::zero(), Size::new(8, 3)),
+            2,
+            &[
+                " WWWWWW ", //
+                "KKKKKKKK", //
+                " KKKKKK ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn bevel_with_outline() {
+        let mut display = MockDisplay::new();
+        segment(Rectangle::new(Point::zero(), Size::new(12, 7)), Rgb888::RED)
+            .with_rendering(SegmentRendering::Outline { stroke_width: 2 })
+            .with_bevel(Some(Bevel::new(1, Rgb888::WHITE, Rgb888::BLACK)))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "   WWWWWW   ", //
+            "  WRRRRRRK  ", //
+            " WR      RK ", //
+            "WR        RK", //
+            " WR      RK ", //
+            "  WRRRRRRK  ", //
+            "   KKKKKK   ", //
+        ]);
+    }
+
+    #[test]
+    fn scaled_thin_horizontal() {
+        let rect = Rectangle::new(Point::zero(), Size::new(9, 3));
+
+        let mut display = MockDisplay::new();
+        segment(rect, BinaryColor::On)
+            .scaled(64)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "     ", //
+            "   ##", //
+        ]);
+    }
 }
@@ -0,0 +1,364 @@
+use embedded_graphics::{prelude::*, primitives::StyledDrawable, text::Baseline};
+
+use crate::{digit::draw_digits, glyph::Glyph, Segments, SevenSegmentStyle};
+
+/// Bar fill order.
+///
+/// The fill order defines the sequence of steps in which segments are lit when the value of a
+/// [`SegmentBar`] increases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum BarFillOrder<'a> {
+    /// Vertical segment pairs from left to right.
+    ///
+    /// Each digit contributes two steps: the left segments `E` and `F` followed by the right
+    /// segments `B` and `C`.
+    #[default]
+    Columns,
+
+    /// Horizontal segments from bottom to top.
+    ///
+    /// The bar has three steps, which light the `D`, `G` and `A` segments in all digits.
+    Rows,
+
+    /// Custom fill order.
+    ///
+    /// Each step lights the segments in the digit with the given index. Steps which refer to
+    /// digits outside the bar are ignored.
+    Custom(&'a [(usize, Segments)]),
+}
+
+/// Bar graph.
+///
+/// A segment bar maps a value in a range to a number of lit segments in `N` adjacent digits.
+/// The optional peak hold marks the highest recent value for a configurable time.
+///
+/// The position is the top left corner of the leftmost digit.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{SegmentBar, SevenSegmentStyleBuilder};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .inactive_segment_color(Rgb888::new(0, 32, 0))
+///     .build();
+///
+/// let mut vu_meter = SegmentBar::<4>::new(-60, 0, Point::new(5, 5)).with_peak_hold(1000);
+///
+/// let time_ms = 250;
+/// vu_meter.set_value(-12, time_ms);
+/// vu_meter.draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SegmentBar<'a, const N: usize> {
+    min: i32,
+    max: i32,
+    position: Point,
+    fill_order: BarFillOrder<'a>,
+    peak_hold_ms: Option<u32>,
+    value: i32,
+    peak: Option<(i32, u32)>,
+}
+
+impl<'a, const N: usize> SegmentBar<'a, N> {
+    /// Creates a new segment bar.
+    ///
+    /// The bar is empty for values less than or equal to `min` and full for values greater than
+    /// or equal to `max`. Between these values the range is divided into equal parts, one for
+    /// each step of the fill order, and a step is lit once the value reaches the upper bound of
+    /// its part.
+    pub fn new(min: i32, max: i32, position: Point) -> Self {
+        Self {
+            min,
+            max,
+            position,
+            fill_order: BarFillOrder::default(),
+            peak_hold_ms: None,
+            value: min,
+            peak: None,
+        }
+    }
+
+    /// Sets the fill order.
+    pub fn with_fill_order(mut self, fill_order: BarFillOrder<'a>) -> Self {
+        self.fill_order = fill_order;
+
+        self
+    }
+
+    /// Enables the peak hold.
+    ///
+    /// The peak is held for `hold_ms` milliseconds, unless it is exceeded by a new value.
+    pub fn with_peak_hold(mut self, hold_ms: u32) -> Self {
+        self.peak_hold_ms = Some(hold_ms);
+
+        self
+    }
+
+    /// Sets the value.
+    ///
+    /// `time_ms` is the current time in milliseconds, which is used for the peak hold.
+    pub fn set_value(&mut self, value: i32, time_ms: u32) {
+        self.value = value;
+
+        let Some(hold_ms) = self.peak_hold_ms else {
+            return;
+        };
+
+        match self.peak {
+            Some((peak, since)) if value < peak && time_ms.wrapping_sub(since) < hold_ms => {}
+            _ => self.peak = Some((value, time_ms)),
+        }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Returns the held peak value.
+    ///
+    /// `None` is returned if the peak hold is disabled.
+    pub fn peak(&self) -> Option<i32> {
+        self.peak.map(|(peak, _)| peak)
+    }
+
+    /// Returns the active segments of all digits.
+    pub fn segments(&self) -> [Segments; N] {
+        let mut digits = [Segments::empty(); N];
+
+        for step in 0..self.level(self.value) {
+            self.light_step(step, &mut digits);
+        }
+
+        if let Some(peak) = self.peak().map(|peak| self.level(peak)).filter(|l| *l > 0) {
+            self.light_step(peak - 1, &mut digits);
+        }
+
+        digits
+    }
+
+    /// Returns the number of steps.
+    fn step_count(&self) -> usize {
+        match self.fill_order {
+            BarFillOrder::Columns => 2 * N,
+            BarFillOrder::Rows => ROWS.len(),
+            BarFillOrder::Custom(steps) => steps.len(),
+        }
+    }
+
+    /// Returns the number of lit steps for a value.
+    ///
+    /// The range is divided into equal parts, one for each step. A step is lit once the value
+    /// reaches the upper bound of its part.
+    fn level(&self, value: i32) -> usize {
+        let steps = self.step_count() as i64;
+        let span = i64::from(self.max) - i64::from(self.min);
+
+        if span <= 0 {
+            return if value >= self.max { steps as usize } else { 0 };
+        }
+
+        let offset = (i64::from(value) - i64::from(self.min)).clamp(0, span);
+
+        (offset * steps / span) as usize
+    }
+
+    /// Lights the segments of a single step.
+    fn light_step(&self, step: usize, digits: &mut [Segments; N]) {
+        match self.fill_order {
+            BarFillOrder::Columns => {
+                digits[step / 2] |= if step.is_multiple_of(2) {
+                    Segments::E | Segments::F
+                } else {
+                    Segments::B | Segments::C
+                };
+            }
+            BarFillOrder::Rows => {
+                for digit in digits.iter_mut() {
+                    *digit |= ROWS[step];
+                }
+            }
+            BarFillOrder::Custom(steps) => {
+                if let Some(digit) = digits.get_mut(steps[step].0) {
+                    *digit |= steps[step].1;
+                }
+            }
+        }
+    }
+}
+
+/// Steps of the `Rows` fill order.
+const ROWS: [Segments; 3] = [Segments::D, Segments::G, Segments::A];
+
+impl<C: PixelColor, const N: usize> StyledDrawable<SevenSegmentStyle<C>> for SegmentBar<'_, N> {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let text_bounds = style
+            .measure_glyphs(
+                (0..N).map(|_| Glyph::Digit(Segments::empty())),
+                self.position,
+                Baseline::Top,
+            )
+            .bounding_box;
+
+        style.draw_backgrounds(
+            (0..N).map(|_| style.digit_size.width),
+            self.position,
+            target,
+        )?;
+
+        draw_digits(
+            self.segments().into_iter(),
+            self.position,
+            style,
+            text_bounds,
+            target,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SevenSegmentStyleBuilder;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    const NONE: Segments = Segments::empty();
+    const LEFT: Segments = Segments::E.union(Segments::F);
+    const RIGHT: Segments = Segments::B.union(Segments::C);
+
+    fn bar_segments<const N: usize>(mut bar: SegmentBar<'_, N>, value: i32) -> [Segments; N] {
+        bar.set_value(value, 0);
+        bar.segments()
+    }
+
+    #[test]
+    fn columns() {
+        let bar = SegmentBar::<2>::new(0, 100, Point::zero());
+
+        assert_eq!(bar_segments(bar, -10), [NONE, NONE]);
+        assert_eq!(bar_segments(bar, 0), [NONE, NONE]);
+        assert_eq!(bar_segments(bar, 25), [LEFT, NONE]);
+        assert_eq!(bar_segments(bar, 40), [LEFT, NONE]);
+        assert_eq!(bar_segments(bar, 50), [LEFT | RIGHT, NONE]);
+        assert_eq!(bar_segments(bar, 75), [LEFT | RIGHT, LEFT]);
+        assert_eq!(bar_segments(bar, 100), [LEFT | RIGHT, LEFT | RIGHT]);
+        assert_eq!(bar_segments(bar, 200), [LEFT | RIGHT, LEFT | RIGHT]);
+    }
+
+    #[test]
+    fn step_boundaries() {
+        let bar = SegmentBar::<2>::new(-10, 0, Point::zero());
+
+        // The upper bounds of the four steps are -7.5, -5, -2.5 and 0.
+        assert_eq!(bar_segments(bar, -8), [NONE, NONE]);
+        assert_eq!(bar_segments(bar, -7), [LEFT, NONE]);
+        assert_eq!(bar_segments(bar, -6), [LEFT, NONE]);
+        assert_eq!(bar_segments(bar, -5), [LEFT | RIGHT, NONE]);
+        assert_eq!(bar_segments(bar, -3), [LEFT | RIGHT, NONE]);
+        assert_eq!(bar_segments(bar, -2), [LEFT | RIGHT, LEFT]);
+        assert_eq!(bar_segments(bar, -1), [LEFT | RIGHT, LEFT]);
+        assert_eq!(bar_segments(bar, 0), [LEFT | RIGHT, LEFT | RIGHT]);
+    }
+
+    #[test]
+    fn rows() {
+        let bar = SegmentBar::<2>::new(0, 3, Point::zero()).with_fill_order(BarFillOrder::Rows);
+
+        assert_eq!(bar_segments(bar, 0), [NONE, NONE]);
+        assert_eq!(bar_segments(bar, 1), [Segments::D, Segments::D]);
+        assert_eq!(
+            bar_segments(bar, 3),
+            [
+                Segments::D | Segments::G | Segments::A,
+                Segments::D | Segments::G | Segments::A
+            ]
+        );
+    }
+
+    #[test]
+    fn custom() {
+        let steps = [(1, Segments::D), (0, Segments::D), (5, Segments::A)];
+        let bar =
+            SegmentBar::<2>::new(0, 3, Point::zero()).with_fill_order(BarFillOrder::Custom(&steps));
+
+        assert_eq!(bar_segments(bar, 1), [NONE, Segments::D]);
+        assert_eq!(bar_segments(bar, 3), [Segments::D, Segments::D]);
+    }
+
+    #[test]
+    fn empty_range() {
+        let bar = SegmentBar::<1>::new(10, 10, Point::zero());
+
+        assert_eq!(bar_segments(bar, 9), [NONE]);
+        assert_eq!(bar_segments(bar, 10), [LEFT | RIGHT]);
+    }
+
+    #[test]
+    fn peak_hold() {
+        let mut bar = SegmentBar::<3>::new(0, 6, Point::zero()).with_peak_hold(100);
+        assert_eq!(bar.peak(), None);
+
+        bar.set_value(5, 0);
+        assert_eq!(bar.segments(), [LEFT | RIGHT, LEFT | RIGHT, LEFT]);
+
+        bar.set_value(1, 50);
+        assert_eq!(bar.peak(), Some(5));
+        assert_eq!(bar.segments(), [LEFT, NONE, LEFT]);
+
+        bar.set_value(2, 100);
+        assert_eq!(bar.peak(), Some(2));
+        assert_eq!(bar.segments(), [LEFT | RIGHT, NONE, NONE]);
+
+        bar.set_value(3, 110);
+        assert_eq!(bar.peak(), Some(3));
+    }
+
+    #[test]
+    fn draw() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(BinaryColor::On)
+            .inactive_segment_color(BinaryColor::Off)
+            .build();
+
+        let mut bar = SegmentBar::<2>::new(0, 4, Point::zero());
+        bar.set_value(3, 0);
+
+        let mut display = MockDisplay::new();
+        let next = bar.draw_styled(&style, &mut display).unwrap();
+        assert_eq!(next, Point::new(12, 0));
+
+        display.assert_pattern(&[
+            " ...   ... ", //
+            "#   # #   .", //
+            "#   # #   .", //
+            "#   # #   .", //
+            " ...   ... ", //
+            "#   # #   .", //
+            "#   # #   .", //
+            "#   # #   .", //
+            " ...   ... ", //
+        ]);
+    }
+}
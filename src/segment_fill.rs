@@ -0,0 +1,389 @@
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use crate::interpolate::Blending;
+
+/// Segment fill.
+///
+/// The segment fill defines the colors of active segments. In addition to solid colors it
+/// supports gradients and palettes, which assign different colors to the characters of a
+/// string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SegmentFill<C> {
+    /// Solid color.
+    Solid(C),
+
+    /// Linear gradient.
+    LinearGradient {
+        /// The color at the start of the gradient.
+        start: C,
+
+        /// The color at the end of the gradient.
+        end: C,
+
+        /// The gradient direction.
+        direction: GradientDirection,
+    },
+
+    /// Palette with one color per digit.
+    ///
+    /// The first digit of a string uses the first palette color, the second digit the second
+    /// color and so on. The colors are repeated if the string contains more digits than there
+    /// are colors in the palette. Colons and decimal points don't count as digits and use the
+    /// color of the preceding digit.
+    Palette(Palette<C>),
+}
+
+/// Color palette.
+///
+/// A palette contains between one and [`MAX_COLORS`](Self::MAX_COLORS) colors. The colors are
+/// stored inside the palette, which makes it possible to create palettes at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Palette<C> {
+    colors: [C; Palette::<()>::MAX_COLORS],
+    len: usize,
+}
+
+impl<C> Palette<C> {
+    /// The maximum number of colors in a palette.
+    pub const MAX_COLORS: usize = 16;
+}
+
+impl<C: Copy> Palette<C> {
+    /// Creates a new palette.
+    ///
+    /// Returns `None` if `colors` is empty or contains more than
+    /// [`MAX_COLORS`](Self::MAX_COLORS) colors.
+    pub fn new(colors: &[C]) -> Option<Self> {
+        let first = *colors.first()?;
+        if colors.len() > Self::MAX_COLORS {
+            return None;
+        }
+
+        let mut array = [first; Palette::<()>::MAX_COLORS];
+        array[..colors.len()].copy_from_slice(colors);
+
+        Some(Self {
+            colors: array,
+            len: colors.len(),
+        })
+    }
+
+    /// Returns the colors.
+    pub fn colors(&self) -> &[C] {
+        &self.colors[..self.len]
+    }
+
+    /// Returns the color for the digit with the given index.
+    fn color(&self, index: usize) -> C {
+        self.colors[index % self.len]
+    }
+}
+
+impl<C: PixelColor> SegmentFill<C> {
+    /// Resolves the fill for a single character.
+    ///
+    /// `cell` is the bounding box of the character, `text` is the bounding box of the string the
+    /// character is part of and `index` is the position of the digit inside the string.
+    /// Gradients use `blending` to interpolate between the start and end colors.
+    pub(crate) fn resolve(
+        &self,
+        cell: Rectangle,
+        text: Rectangle,
+        index: usize,
+        blending: Blending<C>,
+    ) -> Option<ResolvedFill<C>> {
+        match *self {
+            SegmentFill::Solid(color) => Some(ResolvedFill::Solid(color)),
+            SegmentFill::LinearGradient {
+                start,
+                end,
+                direction,
+            } => {
+                let (origin, length) = match direction {
+                    GradientDirection::Vertical => (cell.top_left.y, cell.size.height),
+                    GradientDirection::Horizontal => (text.top_left.x, text.size.width),
+                };
+
+                Some(ResolvedFill::Gradient {
+                    start,
+                    end,
+                    origin,
+                    length,
+                    direction,
+                    blending,
+                })
+            }
+            SegmentFill::Palette(palette) => Some(ResolvedFill::Solid(palette.color(index))),
+        }
+    }
+}
+
+/// Gradient direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GradientDirection {
+    /// Top to bottom gradient across the height of each digit.
+    Vertical,
+
+    /// Left to right gradient across the width of the whole string.
+    ///
+    /// If a [`Digit`](crate::Digit) is drawn on its own the gradient spans the width of the
+    /// digit.
+    Horizontal,
+}
+
+/// Segment fill resolved for a single character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResolvedFill<C> {
+    /// Solid color.
+    Solid(C),
+
+    /// Linear gradient.
+    Gradient {
+        start: C,
+        end: C,
+        origin: i32,
+        length: u32,
+        direction: GradientDirection,
+        blending: Blending<C>,
+    },
+}
+
+impl<C: PixelColor> ResolvedFill<C> {
+    /// Returns the color at the given point.
+    pub fn color_at(&self, point: Point) -> C {
+        match *self {
+            ResolvedFill::Solid(color) => color,
+            ResolvedFill::Gradient {
+                start,
+                end,
+                origin,
+                length,
+                direction,
+                blending,
+            } => {
+                let position = match direction {
+                    GradientDirection::Vertical => point.y,
+                    GradientDirection::Horizontal => point.x,
+                };
+
+                let max = i64::from(length.saturating_sub(1).max(1));
+                let offset = i64::from(position - origin).clamp(0, max);
+
+                blending.interpolate(start, end, (offset * 255 / max) as u8)
+            }
+        }
+    }
+
+    /// Returns the fill with reduced brightness.
+    pub fn dimmed(self, brightness: u8, blending: Blending<C>) -> Self {
+        if brightness == u8::MAX {
+            return self;
+        }
+
+        self.map_colors(|color| blending.dim(color, brightness))
+    }
+
+    /// Applies a function to all colors of the fill.
+    pub fn map_colors(self, f: impl Fn(C) -> C) -> Self {
+        match self {
+            ResolvedFill::Solid(color) => ResolvedFill::Solid(f(color)),
+            ResolvedFill::Gradient {
+                start,
+                end,
+                origin,
+                length,
+                direction,
+                blending,
+            } => ResolvedFill::Gradient {
+                start: f(start),
+                end: f(end),
+                origin,
+                length,
+                direction,
+                blending,
+            },
+        }
+    }
+
+    /// Fills a rectangle.
+    pub fn fill<D>(&self, rect: &Rectangle, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let uniform = match self {
+            ResolvedFill::Solid(_) => true,
+            ResolvedFill::Gradient { direction, .. } => match direction {
+                GradientDirection::Vertical => rect.size.height <= 1,
+                GradientDirection::Horizontal => rect.size.width <= 1,
+            },
+        };
+
+        if uniform {
+            target.fill_solid(rect, self.color_at(rect.top_left))
+        } else {
+            target.fill_contiguous(rect, rect.points().map(|point| self.color_at(point)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Digit, Segments, SevenSegmentStyleBuilder};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::Gray4,
+        text::{Baseline, Text},
+    };
+
+    fn test_fill(segment_fill: SegmentFill<Gray4>, text: &str, expected_pattern: &[&str]) {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(Gray4::WHITE)
+            .segment_fill(segment_fill)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline(text, Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(expected_pattern);
+    }
+
+    #[test]
+    fn vertical_gradient() {
+        test_fill(
+            SegmentFill::LinearGradient {
+                start: Gray4::new(0x7),
+                end: Gray4::new(0xF),
+                direction: GradientDirection::Vertical,
+            },
+            "8.8",
+            &[
+                " 777     777 ", //
+                "8   8   8   8", //
+                "9   9   9   9", //
+                "A   A   A   A", //
+                " BBB     BBB ", //
+                "C   C   C   C", //
+                "D   D   D   D", //
+                "E   E   E   E", //
+                " FFF  F  FFF ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn horizontal_gradient() {
+        test_fill(
+            SegmentFill::LinearGradient {
+                start: Gray4::new(0x0),
+                end: Gray4::new(0xF),
+                direction: GradientDirection::Horizontal,
+            },
+            "8:8",
+            &[
+                " 124     BCE ", //
+                "0   5   A   F", //
+                "0   5   A   F", //
+                "0   5 7 A   F", //
+                " 124     BCE ", //
+                "0   5   A   F", //
+                "0   5 7 A   F", //
+                "0   5   A   F", //
+                " 124     BCE ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn palette() {
+        let palette = Palette::new(&[Gray4::new(0x3), Gray4::new(0x9)]).unwrap();
+
+        test_fill(
+            SegmentFill::Palette(palette),
+            "777",
+            &[
+                " 333   999   333 ", //
+                "    3     9     3", //
+                "    3     9     3", //
+                "    3     9     3", //
+                "                 ", //
+                "    3     9     3", //
+                "    3     9     3", //
+                "    3     9     3", //
+            ],
+        );
+    }
+
+    #[test]
+    fn palette_separators() {
+        let palette = Palette::new(&[Gray4::new(0x3), Gray4::new(0x9)]).unwrap();
+
+        test_fill(
+            SegmentFill::Palette(palette),
+            "1.:1",
+            &[
+                "               ", //
+                "    3         9", //
+                "    3         9", //
+                "    3   3     9", //
+                "               ", //
+                "    3         9", //
+                "    3   3     9", //
+                "    3         9", //
+                "      3        ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn new_palette() {
+        let colors = [Gray4::new(0x1); Palette::<Gray4>::MAX_COLORS + 1];
+
+        assert_eq!(Palette::<Gray4>::new(&[]), None);
+        assert_eq!(Palette::new(&colors), None);
+        assert_eq!(
+            Palette::new(&colors[1..]).map(|palette| palette.colors().len()),
+            Some(Palette::<Gray4>::MAX_COLORS)
+        );
+        assert_eq!(
+            Palette::new(&[Gray4::new(0x5)]).map(|palette| palette.color(3)),
+            Some(Gray4::new(0x5))
+        );
+    }
+
+    #[test]
+    fn horizontal_gradient_digit() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .segment_width(1)
+            .segment_fill(SegmentFill::LinearGradient {
+                start: Gray4::new(0x0),
+                end: Gray4::new(0xF),
+                direction: GradientDirection::Horizontal,
+            })
+            .build();
+
+        let mut display = MockDisplay::new();
+        Digit::new(Segments::A | Segments::D | Segments::G, Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " 47B", //
+            "    ", //
+            "    ", //
+            "    ", //
+            " 47B", //
+            "    ", //
+            "    ", //
+            "    ", //
+            " 47B", //
+        ]);
+    }
+}
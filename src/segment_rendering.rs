@@ -0,0 +1,16 @@
+/// Segment rendering mode.
+///
+/// The rendering mode defines if segments are drawn as filled shapes or only by their
+/// outlines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum SegmentRendering {
+    /// Segments are filled.
+    #[default]
+    Filled,
+
+    /// Only the outline of each segment is drawn.
+    Outline {
+        /// The stroke width of the outline.
+        stroke_width: u32,
+    },
+}
@@ -1,20 +1,30 @@
-use core::convert::TryFrom;
-
 use embedded_graphics::{
     prelude::*,
-    primitives::{Rectangle, StyledDrawable},
+    primitives::Rectangle,
     text::{
         renderer::{CharacterStyle, TextMetrics, TextRenderer},
         Baseline,
     },
 };
 
-use crate::{Digit, Segments};
+use crate::{
+    glyph::Glyph,
+    interpolate::{combine_brightness, Blending},
+    segment_fill::ResolvedFill,
+    Bevel, Digit, DropShadow, Ghost, Glow, SegmentFill, SegmentRendering, Segments, TextFlow,
+    UnsupportedCharacterPolicy,
+};
 
 /// Seven-segment character style.
 ///
 /// Use [`SevenSegmentStyleBuilder`] to build styles.
 ///
+/// Styles can be used with any color type. Effects which blend colors, like glows, gradients,
+/// ghost segments and brightness, need a color type which implements
+/// [`Interpolate`](crate::Interpolate). Styles for other color types are created with
+/// [`SevenSegmentStyleBuilder::without_interpolation`], which replaces blended colors by the
+/// nearer of both colors and ignores the brightness.
+///
 /// [`SevenSegmentStyleBuilder`]: struct.SevenSegmentStyleBuilder.html
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[non_exhaustive]
@@ -31,18 +41,201 @@ pub struct SevenSegmentStyle<C> {
     /// The color of active segments.
     pub segment_color: Option<C>,
 
+    /// The fill of active segments.
+    ///
+    /// If a fill is set it is used instead of the segment color. Setting the text color with
+    /// [`CharacterStyle::set_text_color`], e.g. by a text layout that changes colors, removes the
+    /// fill to make sure the new color is visible.
+    pub segment_fill: Option<SegmentFill<C>>,
+
     /// The color of inactive segments.
     pub inactive_segment_color: Option<C>,
+
+    /// Ghost segments.
+    ///
+    /// If ghost segments are enabled, the color of inactive segments is derived from the active
+    /// segment fill and the inactive segment color is ignored.
+    pub ghost: Option<Ghost<C>>,
+
+    /// The rendering mode of active segments.
+    pub segment_rendering: SegmentRendering,
+
+    /// The rendering mode of inactive segments.
+    pub inactive_segment_rendering: SegmentRendering,
+
+    /// The background color.
+    ///
+    /// If a background color is set, the bounding box of each character, including the spacing
+    /// to the next character, is filled with this color. The backgrounds of all characters in a
+    /// string are drawn first, to keep effects which extend into neighboring characters
+    /// visible.
+    pub background_color: Option<C>,
+
+    /// The glow effect around active segments.
+    pub glow: Option<Glow<C>>,
+
+    /// The drop shadow of active segments.
+    pub drop_shadow: Option<DropShadow<C>>,
+
+    /// The bevel of active segments.
+    pub bevel: Option<Bevel<C>>,
+
+    /// The text flow direction.
+    pub text_flow: TextFlow,
+
+    /// The fixed cell width.
+    ///
+    /// If a cell width is set, every character, including colons and decimal points, occupies
+    /// a cell of this width and is horizontally centered inside the cell. This keeps the width
+    /// of a string constant when the characters change. Cells which are narrower than a
+    /// character are widened to the character width.
+    ///
+    /// The cell width is ignored in vertical text flow, where every character already occupies
+    /// a cell of the digit height.
+    pub cell_width: Option<u32>,
+
+    /// Proportional digits.
+    ///
+    /// If enabled, digits which only use the B and C or the E and F segments, like `1`, are only
+    /// as wide as a segment. Narrow digits are drawn without inactive segments, because there is
+    /// no room for them.
+    pub proportional: bool,
+
+    /// The spacing between lines.
+    ///
+    /// If no line spacing is set, the digit spacing is used. In vertical text flow the line
+    /// spacing is the spacing between the columns of a [`TextBlock`](crate::TextBlock).
+    pub line_spacing: Option<u32>,
+
+    /// The policy for characters which can't be displayed.
+    ///
+    /// The policy is applied to strings drawn by the [`TextRenderer`] implementation.
+    pub unsupported_character: UnsupportedCharacterPolicy,
+
+    /// The brightness of active and inactive segments.
+    ///
+    /// A brightness of `255` draws segments with unchanged colors and lower values dim the
+    /// colors towards black. The colors of bevels and drop shadows are dimmed as well.
+    ///
+    /// The brightness applies to all characters drawn with this style. Individual digits can be
+    /// dimmed further with [`Digit::with_brightness`], which isn't available for text.
+    pub brightness: u8,
+
+    /// The color blending functions used by effects.
+    pub(crate) blending: Blending<C>,
 }
 
 impl<C: PixelColor> SevenSegmentStyle<C> {
-    /// Returns the fill color for the given segment state.
-    pub(crate) fn state_color(&self, state: bool) -> Option<C> {
+    /// Returns the rendering mode for the given segment state.
+    pub(crate) fn state_rendering(&self, state: bool) -> SegmentRendering {
         if state {
-            self.segment_color
+            self.segment_rendering
         } else {
-            self.inactive_segment_color
+            self.inactive_segment_rendering
+        }
+    }
+
+    /// Returns the width of a glyph, including the padding of fixed width cells.
+    pub(crate) fn glyph_width(&self, glyph: Glyph) -> u32 {
+        let width = self.natural_glyph_width(glyph);
+
+        match (self.text_flow, self.cell_width) {
+            (TextFlow::Horizontal, Some(cell_width)) => cell_width.max(width),
+            _ => width,
+        }
+    }
+
+    /// Returns the width of a glyph, ignoring the cell width.
+    fn natural_glyph_width(&self, glyph: Glyph) -> u32 {
+        match glyph {
+            Glyph::Colon | Glyph::DecimalPoint => self.segment_width,
+            Glyph::Digit(segments) if self.is_narrow(segments) => self.segment_width,
+            Glyph::Digit(_) | Glyph::Empty | Glyph::Unsupported => self.digit_size.width,
+        }
+    }
+
+    /// Returns `true` if a digit is drawn narrower in proportional mode.
+    fn is_narrow(&self, segments: Segments) -> bool {
+        self.proportional
+            && !segments.is_empty()
+            && ((Segments::B | Segments::C).contains(segments)
+                || (Segments::E | Segments::F).contains(segments))
+    }
+
+    /// Returns the offset between a character with the given width and the next character.
+    pub(crate) fn advance(&self, width: u32) -> Size {
+        match self.text_flow {
+            TextFlow::Horizontal => Size::new(width + self.digit_spacing, 0),
+            TextFlow::Vertical => Size::new(0, self.digit_size.height + self.digit_spacing),
+        }
+    }
+
+    /// Fills the background of a character with the given width.
+    pub(crate) fn draw_background<D>(
+        &self,
+        position: Point,
+        width: u32,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(color) = self.background_color {
+            let size = match self.text_flow {
+                TextFlow::Horizontal => {
+                    Size::new(width + self.digit_spacing, self.digit_size.height)
+                }
+                TextFlow::Vertical => Size::new(
+                    self.digit_size.width,
+                    self.digit_size.height + self.digit_spacing,
+                ),
+            };
+
+            target.fill_solid(&Rectangle::new(position, size), color)?;
         }
+
+        Ok(())
+    }
+
+    /// Returns the spacing between lines.
+    fn line_spacing(&self) -> u32 {
+        self.line_spacing.unwrap_or(self.digit_spacing)
+    }
+
+    /// Returns the distance between the origins of adjacent lines.
+    ///
+    /// In vertical text flow lines are placed as columns and the distance is measured
+    /// horizontally.
+    pub(crate) fn line_pitch(&self) -> u32 {
+        let depth = match self.text_flow {
+            TextFlow::Horizontal => self.digit_size.height,
+            TextFlow::Vertical => self.digit_size.width,
+        };
+
+        depth + self.line_spacing()
+    }
+
+    /// Fills the backgrounds of a sequence of characters with the given widths.
+    pub(crate) fn draw_backgrounds<I, D>(
+        &self,
+        widths: I,
+        mut position: Point,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        I: Iterator<Item = u32>,
+        D: DrawTarget<Color = C>,
+    {
+        if self.background_color.is_none() {
+            return Ok(());
+        }
+
+        for width in widths {
+            self.draw_background(position, width, target)?;
+            position += self.advance(width);
+        }
+
+        Ok(())
     }
 
     /// Returns the vertical offset between the line position and the top edge of the bounding box.
@@ -55,111 +248,388 @@ impl<C: PixelColor> SevenSegmentStyle<C> {
             Baseline::Middle => bottom / 2,
         }
     }
-}
 
-impl<C: PixelColor> CharacterStyle for SevenSegmentStyle<C> {
-    type Color = C;
+    /// Returns the glyphs of a string after applying the unsupported character policy.
+    pub(crate) fn string_glyphs<'a>(
+        &self,
+        text: &'a str,
+    ) -> impl Iterator<Item = Glyph> + Clone + 'a {
+        let policy = self.unsupported_character;
 
-    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
-        self.segment_color = text_color;
+        text.chars()
+            .filter_map(move |c| policy.apply(Glyph::from(c)))
+    }
+
+    /// Measures a sequence of glyphs.
+    pub(crate) fn measure_glyphs<I>(
+        &self,
+        glyphs: I,
+        position: Point,
+        baseline: Baseline,
+    ) -> TextMetrics
+    where
+        I: Iterator<Item = Glyph> + Clone,
+    {
+        let size = match self.text_flow {
+            TextFlow::Horizontal => {
+                let width = glyphs
+                    .map(|glyph| self.glyph_width(glyph) + self.digit_spacing)
+                    .sum::<u32>()
+                    .saturating_sub(self.digit_spacing);
+
+                Size::new(width, self.digit_size.height)
+            }
+            TextFlow::Vertical => {
+                let width = glyphs
+                    .clone()
+                    .map(|glyph| self.glyph_width(glyph))
+                    .max()
+                    .unwrap_or(0);
+                let height = (glyphs.count() as u32
+                    * (self.digit_size.height + self.digit_spacing))
+                    .saturating_sub(self.digit_spacing);
+
+                Size::new(width, height)
+            }
+        };
+
+        let bounding_box = Rectangle::new(
+            position - Size::new(0, self.baseline_offset(baseline)),
+            size,
+        );
+        let next_position = match self.text_flow {
+            TextFlow::Horizontal => position + size.x_axis(),
+            TextFlow::Vertical => position + size.y_axis(),
+        };
+
+        TextMetrics {
+            bounding_box,
+            next_position,
+        }
     }
 }
 
-impl<C: PixelColor> TextRenderer for SevenSegmentStyle<C> {
-    type Color = C;
+impl<C: PixelColor> SevenSegmentStyle<C> {
+    /// Returns the fill of active segments.
+    fn active_fill(
+        &self,
+        cell: Rectangle,
+        text: Rectangle,
+        index: usize,
+    ) -> Option<ResolvedFill<C>> {
+        self.segment_fill
+            .or(self.segment_color.map(SegmentFill::Solid))?
+            .resolve(cell, text, index, self.blending)
+    }
 
-    fn draw_string<D>(
+    /// Returns the fill for the given segment state.
+    ///
+    /// `cell` is the bounding box of the character, `text` is the bounding box of the string the
+    /// character is part of and `index` is the position of the character inside the string. The
+    /// style brightness is combined with the additional `brightness` of the character.
+    pub(crate) fn state_fill(
         &self,
-        text: &str,
+        state: bool,
+        cell: Rectangle,
+        text: Rectangle,
+        index: usize,
+        brightness: u8,
+    ) -> Option<ResolvedFill<C>> {
+        let fill = if state {
+            self.active_fill(cell, text, index)?
+        } else if let Some(ghost) = self.ghost {
+            let fill = self.active_fill(cell, text, index)?;
+
+            match ghost.background_color.or(self.background_color) {
+                Some(background_color) => fill.map_colors(|color| {
+                    self.blending
+                        .interpolate(background_color, color, ghost.opacity)
+                }),
+                None => fill.dimmed(ghost.opacity, self.blending),
+            }
+        } else {
+            ResolvedFill::Solid(self.inactive_segment_color?)
+        };
+
+        Some(fill.dimmed(
+            combine_brightness(self.brightness, brightness),
+            self.blending,
+        ))
+    }
+
+    /// Returns an effect color with reduced brightness.
+    ///
+    /// The style brightness is combined with the additional `brightness` of the character, in
+    /// the same way as for the segment fills.
+    pub(crate) fn dimmed_color(&self, color: C, brightness: u8) -> C {
+        match combine_brightness(self.brightness, brightness) {
+            u8::MAX => color,
+            brightness => self.blending.dim(color, brightness),
+        }
+    }
+
+    /// Draws a sequence of glyphs.
+    ///
+    /// Each glyph is paired with a set of hidden segments, which are drawn as inactive segments.
+    /// Colons and decimal points are hidden if any segment is set.
+    pub(crate) fn draw_glyphs<I, D>(
+        &self,
+        glyphs: I,
         mut position: Point,
         baseline: Baseline,
         target: &mut D,
     ) -> Result<Point, D::Error>
     where
+        I: Iterator<Item = (Glyph, Segments)> + Clone,
         D: DrawTarget<Color = C>,
     {
         position -= Size::new(0, self.baseline_offset(baseline));
 
-        for c in text.chars() {
-            if let Ok(segments) = Segments::try_from(c) {
-                position = Digit::new(segments, position).draw_styled(self, target)?;
-            } else if c == ':' {
-                if let Some(color) = self.segment_color {
+        let text_bounds = self
+            .measure_glyphs(
+                glyphs.clone().map(|(glyph, _)| glyph),
+                position,
+                Baseline::Top,
+            )
+            .bounding_box;
+
+        // The backgrounds are drawn before the glyphs to make sure they don't cover effects,
+        // like glows and drop shadows, which spill into neighboring cells.
+        self.draw_backgrounds(
+            glyphs.clone().map(|(glyph, _)| self.glyph_width(glyph)),
+            position,
+            target,
+        )?;
+
+        // The glow is drawn in a separate pass, because it can extend into neighboring cells
+        // and would otherwise cover the segments of the previous character.
+        if self.glow.is_some() {
+            self.draw_glyph_pass(glyphs.clone(), position, text_bounds, Pass::Glow, target)?;
+        }
+
+        let mut position =
+            self.draw_glyph_pass(glyphs, position, text_bounds, Pass::Segments, target)?;
+
+        position += Size::new(0, self.baseline_offset(baseline));
+
+        Ok(position)
+    }
+
+    /// Draws one pass of a sequence of glyphs.
+    ///
+    /// Returns the position after the last glyph.
+    fn draw_glyph_pass<I, D>(
+        &self,
+        glyphs: I,
+        mut position: Point,
+        text_bounds: Rectangle,
+        pass: Pass,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        I: Iterator<Item = (Glyph, Segments)>,
+        D: DrawTarget<Color = C>,
+    {
+        // Colons and decimal points use the palette color of the preceding digit.
+        let mut digit_index = 0usize;
+        for (glyph, hidden) in glyphs {
+            let separator = matches!(glyph, Glyph::Colon | Glyph::DecimalPoint);
+            let index = if separator {
+                digit_index.saturating_sub(1)
+            } else {
+                digit_index
+            };
+
+            position =
+                self.draw_glyph((glyph, hidden), position, text_bounds, index, pass, target)?;
+            digit_index += usize::from(!separator);
+        }
+
+        Ok(position)
+    }
+
+    /// Draws a single glyph.
+    ///
+    /// Returns the position of the next glyph.
+    fn draw_glyph<D>(
+        &self,
+        (glyph, hidden): (Glyph, Segments),
+        position: Point,
+        text_bounds: Rectangle,
+        index: usize,
+        pass: Pass,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let cell_width = self.glyph_width(glyph);
+        let width = self.natural_glyph_width(glyph);
+
+        // Pad fixed width cells on both sides.
+        let padding = cell_width - width;
+        let cell_position = position;
+        let position = position + Size::new(padding / 2, 0);
+
+        match glyph {
+            Glyph::Digit(segments) if self.is_narrow(segments) => {
+                // The vertical segments on both sides overlap in a digit which is only as wide
+                // as a segment.
+                let narrow_style = Self {
+                    digit_size: Size::new(width, self.digit_size.height),
+                    inactive_segment_color: None,
+                    ghost: None,
+                    ..*self
+                };
+
+                let digit = Digit::new(segments.difference(hidden), position);
+                narrow_style.draw_digit_pass(digit, text_bounds, index, pass, target)?;
+            }
+            Glyph::Digit(segments) => {
+                let digit = Digit::new(segments.difference(hidden), position);
+                self.draw_digit_pass(digit, text_bounds, index, pass, target)?;
+            }
+            Glyph::Colon if pass == Pass::Segments => {
+                let cell = Rectangle::new(position, Size::new(width, self.digit_size.height));
+                if let Some(fill) = self
+                    .state_fill(true, cell, text_bounds, index, u8::MAX)
+                    .filter(|_| hidden.is_empty())
+                {
                     let dy = self.digit_size.height / 3;
 
                     let mut rect = Rectangle::new(
                         position + Size::new(0, dy - self.segment_width / 2),
                         Size::new(self.segment_width, self.segment_width),
                     );
-                    target.fill_solid(&rect, color)?;
+                    fill.fill(&rect, target)?;
 
                     rect.top_left += Size::new(0, dy);
-                    target.fill_solid(&rect, color)?;
+                    fill.fill(&rect, target)?;
                 }
-
-                position += Size::new(self.segment_width + self.digit_spacing, 0);
-            } else if c == '.' {
-                if let Some(color) = self.segment_color {
+            }
+            Glyph::DecimalPoint if pass == Pass::Segments => {
+                let cell = Rectangle::new(position, Size::new(width, self.digit_size.height));
+                if let Some(fill) = self
+                    .state_fill(true, cell, text_bounds, index, u8::MAX)
+                    .filter(|_| hidden.is_empty())
+                {
                     let rect = Rectangle::new(
                         position + Size::new(0, self.digit_size.height - self.segment_width),
                         Size::new(self.segment_width, self.segment_width),
                     );
-                    target.fill_solid(&rect, color)?;
+                    fill.fill(&rect, target)?;
                 }
-
-                position += Size::new(self.segment_width + self.digit_spacing, 0);
-            } else {
-                position += self.digit_size.x_axis() + Size::new(self.digit_spacing, 0);
             }
+            _ => {}
         }
 
-        position += Size::new(0, self.baseline_offset(baseline));
+        Ok(cell_position + self.advance(cell_width))
+    }
 
-        Ok(position)
+    /// Draws one pass of a digit.
+    fn draw_digit_pass<D>(
+        &self,
+        digit: Digit,
+        text_bounds: Rectangle,
+        index: usize,
+        pass: Pass,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        match pass {
+            Pass::Glow => digit.draw_glow(self, text_bounds, index, target),
+            Pass::Segments => digit
+                .draw_segments(self, text_bounds, index, target)
+                .map(drop),
+        }
+    }
+}
+
+/// Drawing pass of a sequence of glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pass {
+    /// The glow around active segments.
+    Glow,
+
+    /// The segments, including their drop shadows and bevels.
+    Segments,
+}
+
+impl<C: PixelColor> CharacterStyle for SevenSegmentStyle<C> {
+    type Color = C;
+
+    /// Sets the segment color.
+    ///
+    /// This also removes the segment fill, to make sure the text color is used.
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.segment_color = text_color;
+        self.segment_fill = None;
+    }
+}
+
+impl<C: PixelColor> TextRenderer for SevenSegmentStyle<C> {
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let glyphs = self
+            .string_glyphs(text)
+            .map(|glyph| (glyph, Segments::empty()));
+
+        self.draw_glyphs(glyphs, position, baseline, target)
     }
 
     fn draw_whitespace<D>(
         &self,
         width: u32,
         position: Point,
-        _baseline: Baseline,
-        _target: &mut D,
+        baseline: Baseline,
+        target: &mut D,
     ) -> Result<Point, D::Error>
     where
         D: DrawTarget<Color = C>,
     {
-        Ok(position + Size::new(width, 0))
+        let (size, advance) = match self.text_flow {
+            TextFlow::Horizontal => (
+                Size::new(width, self.digit_size.height),
+                Size::new(width, 0),
+            ),
+            TextFlow::Vertical => (Size::new(self.digit_size.width, width), Size::new(0, width)),
+        };
+
+        if let Some(color) = self.background_color {
+            let rect = Rectangle::new(
+                position - Size::new(0, self.baseline_offset(baseline)),
+                size,
+            );
+            target.fill_solid(&rect, color)?;
+        }
+
+        Ok(position + advance)
     }
 
     fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
-        let width = text
-            .chars()
-            .map(|c| {
-                let width = if c == '.' || c == ':' {
-                    self.segment_width
-                } else {
-                    self.digit_size.width
-                };
-
-                width + self.digit_spacing
-            })
-            .sum::<u32>()
-            .saturating_sub(self.digit_spacing);
-
-        let bounding_box = Rectangle::new(
-            position - Size::new(0, self.baseline_offset(baseline)),
-            Size::new(width, self.digit_size.height),
-        );
-        let next_position = position + Size::new(width, 0);
-
-        TextMetrics {
-            bounding_box,
-            next_position,
-        }
+        self.measure_glyphs(self.string_glyphs(text), position, baseline)
     }
 
+    /// Returns the line height.
+    ///
+    /// The line height is the digit height plus the line spacing, which defaults to the digit
+    /// spacing. The same value is used in vertical text flow, because `Text` always places the
+    /// next line below the previous one. The distance between the columns of a `TextBlock` is
+    /// returned by `line_pitch`.
     fn line_height(&self) -> u32 {
-        self.digit_size.height + self.digit_spacing
+        self.digit_size.height + self.line_spacing()
     }
 }
 
@@ -167,7 +637,11 @@ impl<C: PixelColor> TextRenderer for SevenSegmentStyle<C> {
 mod tests {
     use super::*;
     use crate::SevenSegmentStyleBuilder;
-    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor, text::Text};
+    use embedded_graphics::{
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Gray4, Gray8, Rgb888},
+        text::Text,
+    };
 
     fn test_digits(
         character_style: SevenSegmentStyle<BinaryColor>,
@@ -700,6 +1174,379 @@ mod tests {
         );
     }
 
+    #[test]
+    fn vertical_text_flow() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(2)
+            .segment_width(1)
+            .segment_color(BinaryColor::On)
+            .text_flow(TextFlow::Vertical)
+            .build();
+
+        test_digits(
+            style,
+            "12",
+            &[
+                "     ", //
+                "    #", //
+                "    #", //
+                "    #", //
+                "     ", //
+                "    #", //
+                "    #", //
+                "    #", //
+                "     ", //
+                "     ", //
+                "     ", //
+                " ### ", //
+                "    #", //
+                "    #", //
+                "    #", //
+                " ### ", //
+                "#    ", //
+                "#    ", //
+                "#    ", //
+                " ### ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn measure_string_vertical() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(7, 12))
+            .digit_spacing(1)
+            .segment_width(2)
+            .segment_color(BinaryColor::On)
+            .text_flow(TextFlow::Vertical)
+            .build();
+
+        let position = Point::new(1, 2);
+
+        let metrics = style.measure_string("1:2", position, Baseline::Top);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(
+                position,
+                style.digit_size.component_mul(Size::new(1, 3))
+                    + Size::new(0, style.digit_spacing * 2)
+            )
+        );
+        assert_eq!(
+            metrics.next_position,
+            position + metrics.bounding_box.size.y_axis()
+        );
+        assert_eq!(
+            style.line_height(),
+            style.digit_size.height + style.digit_spacing
+        );
+        assert_eq!(
+            style.line_pitch(),
+            style.digit_size.width + style.digit_spacing
+        );
+    }
+
+    #[test]
+    fn cell_width_ignored_in_vertical_flow() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(7, 12))
+            .digit_spacing(1)
+            .segment_width(2)
+            .segment_color(BinaryColor::On)
+            .text_flow(TextFlow::Vertical)
+            .cell_width(11)
+            .build();
+
+        let position = Point::new(1, 2);
+
+        let metrics = style.measure_string("12", position, Baseline::Top);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(position, Size::new(7, 2 * 12 + 1))
+        );
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline("88", position, style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+        let area = display.affected_area();
+        assert_eq!(
+            (area.top_left.x, area.size.width),
+            (position.x, style.digit_size.width)
+        );
+    }
+
+    #[test]
+    fn outlined_active_segments() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(11, 17))
+            .digit_spacing(1)
+            .segment_width(3)
+            .segment_color(BinaryColor::On)
+            .segment_rendering(SegmentRendering::Outline { stroke_width: 1 })
+            .inactive_segment_color(BinaryColor::Off)
+            .build();
+
+        test_digits(
+            style,
+            "7",
+            &[
+                "   #####   ", //
+                "  #     #  ", //
+                " . ##### # ", //
+                "...     # #", //
+                "...     # #", //
+                "...     # #", //
+                "...     # #", //
+                " . ..... # ", //
+                "  .......  ", //
+                " . ..... # ", //
+                "...     # #", //
+                "...     # #", //
+                "...     # #", //
+                "...     # #", //
+                " . ..... # ", //
+                "  .......  ", //
+                "   .....   ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn background_color() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+
+        Text::with_baseline("1:2.W", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            ".........###..........", //
+            "....#.......#.........", //
+            "....#.......#.........", //
+            "....#.#.....#.........", //
+            ".........###..........", //
+            "....#...#.............", //
+            "....#.#.#.............", //
+            "....#...#.............", //
+            ".........###..#.......", //
+        ]);
+    }
+
+    #[test]
+    fn background_color_glow() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(Gray4::WHITE)
+            .background_color(Gray4::new(1))
+            .glow(Glow::new(3).with_background_color(Gray4::new(1)))
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display.set_allow_out_of_bounds_drawing(true);
+
+        Text::with_baseline("1 ", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        // The glow of the first digit extends into the background of the second cell.
+        display.assert_pattern(&[
+            "138ADA831111", //
+            "138DFD831111", //
+            "138DFD831111", //
+            "138DFD831111", //
+            "138ADA831111", //
+            "138DFD831111", //
+            "138DFD831111", //
+            "138DFD831111", //
+            "138ADA831111", //
+            "  68886     ", //
+            "   333      ", //
+        ]);
+    }
+
+    #[test]
+    fn brightness() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(Gray8::new(200))
+            .inactive_segment_color(Gray8::new(100))
+            .brightness(128)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline("1", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(4, 2)), Some(Gray8::new(100)));
+        assert_eq!(display.get_pixel(Point::new(1, 0)), Some(Gray8::new(50)));
+
+        let mut display = MockDisplay::new();
+        Digit::new(Segments::B | Segments::C, Point::zero())
+            .with_brightness(128)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(4, 2)), Some(Gray8::new(50)));
+        assert_eq!(display.get_pixel(Point::new(1, 0)), Some(Gray8::new(25)));
+    }
+
+    #[test]
+    fn brightness_without_builder_brightness() {
+        let mut style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .segment_width(1)
+            .segment_color(Rgb888::new(200, 200, 200))
+            .build();
+
+        let mut display = MockDisplay::new();
+        Digit::new(Segments::B | Segments::C, Point::zero())
+            .with_brightness(128)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(
+            display.get_pixel(Point::new(4, 2)),
+            Some(Rgb888::new(100, 100, 100))
+        );
+
+        style.brightness = 128;
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline("1", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(
+            display.get_pixel(Point::new(4, 2)),
+            Some(Rgb888::new(100, 100, 100))
+        );
+    }
+
+    #[test]
+    fn brightness_effects() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(7, 11))
+            .digit_spacing(1)
+            .segment_width(3)
+            .segment_color(Gray8::new(200))
+            .bevel(Bevel::new(1, Gray8::new(240), Gray8::new(120)))
+            .drop_shadow(DropShadow::new(Point::new(1, 1), Gray8::new(80)))
+            .brightness(128)
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        Digit::new(Segments::A, Point::zero())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(3, 0)), Some(Gray8::new(120)));
+        assert_eq!(display.get_pixel(Point::new(3, 1)), Some(Gray8::new(100)));
+        assert_eq!(display.get_pixel(Point::new(3, 2)), Some(Gray8::new(60)));
+        assert_eq!(display.get_pixel(Point::new(4, 3)), Some(Gray8::new(40)));
+    }
+
+    /// Color type without an `Interpolate` implementation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct PaletteIndex(u8);
+
+    impl PixelColor for PaletteIndex {
+        type Raw = ();
+    }
+
+    #[test]
+    fn color_without_interpolation() {
+        let style = SevenSegmentStyleBuilder::without_interpolation()
+            .digit_size(Size::new(5, 9))
+            .segment_width(1)
+            .segment_color(PaletteIndex(3))
+            .background_color(PaletteIndex(0))
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        Text::with_baseline("1", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(display.get_pixel(Point::new(4, 1)), Some(PaletteIndex(3)));
+        assert_eq!(display.get_pixel(Point::new(0, 1)), Some(PaletteIndex(0)));
+    }
+
+    #[test]
+    fn background_color_whitespace() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        style
+            .draw_whitespace(3, Point::new(1, 8), Baseline::Bottom, &mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " ...", //
+            " ...", //
+            " ...", //
+            " ...", //
+            " ...", //
+            " ...", //
+            " ...", //
+            " ...", //
+            " ...", //
+        ]);
+    }
+
+    #[test]
+    fn background_color_whitespace_vertical() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .text_flow(TextFlow::Vertical)
+            .build();
+
+        let mut display = MockDisplay::new();
+        let next = style
+            .draw_whitespace(3, Point::new(1, 2), Baseline::Top, &mut display)
+            .unwrap();
+        assert_eq!(next, Point::new(1, 5));
+
+        display.assert_pattern(&[
+            "      ", //
+            "      ", //
+            " .....", //
+            " .....", //
+            " .....", //
+        ]);
+    }
+
     #[test]
     fn invalid_char() {
         let style = SevenSegmentStyleBuilder::new()
@@ -725,4 +1572,152 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn cell_width() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .cell_width(5)
+            .segment_color(BinaryColor::On)
+            .build();
+
+        assert_eq!(
+            style.measure_string("1:2.", Point::zero(), Baseline::Top),
+            TextMetrics {
+                bounding_box: Rectangle::new(Point::zero(), Size::new(23, 9)),
+                next_position: Point::new(23, 0),
+            }
+        );
+
+        test_digits(
+            style,
+            "1:2.",
+            &[
+                "             ###     ", //
+                "    #           #    ", //
+                "    #           #    ", //
+                "    #   #       #    ", //
+                "             ###     ", //
+                "    #       #        ", //
+                "    #   #   #        ", //
+                "    #       #        ", //
+                "             ###    #", //
+            ],
+        );
+    }
+
+    #[test]
+    fn proportional() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .proportional(true)
+            .segment_color(BinaryColor::On)
+            .inactive_segment_color(BinaryColor::Off)
+            .build();
+
+        assert_eq!(
+            style.measure_string("11", Point::zero(), Baseline::Top),
+            TextMetrics {
+                bounding_box: Rectangle::new(Point::zero(), Size::new(3, 9)),
+                next_position: Point::new(3, 0),
+            }
+        );
+        assert_eq!(
+            style.measure_string("1I7", Point::zero(), Baseline::Top),
+            TextMetrics {
+                bounding_box: Rectangle::new(Point::zero(), Size::new(9, 9)),
+                next_position: Point::new(9, 0),
+            }
+        );
+
+        test_digits(
+            style,
+            "1I7",
+            &[
+                "     ### ", //
+                "# # .   #", //
+                "# # .   #", //
+                "# # .   #", //
+                "     ... ", //
+                "# # .   #", //
+                "# # .   #", //
+                "# # .   #", //
+                "     ... ", //
+            ],
+        );
+    }
+
+    #[test]
+    fn unsupported_character_policy() {
+        let style = SevenSegmentStyleBuilder::new()
+            .digit_size(Size::new(5, 9))
+            .digit_spacing(1)
+            .segment_width(1)
+            .segment_color(BinaryColor::On)
+            .unsupported_character(UnsupportedCharacterPolicy::Skip)
+            .build();
+        assert_eq!(
+            style.measure_string("0W1", Point::zero(), Baseline::Top),
+            style.measure_string("01", Point::zero(), Baseline::Top),
+        );
+        test_digits(
+            style,
+            "0W1",
+            &[
+                " ###       ", //
+                "#   #     #", //
+                "#   #     #", //
+                "#   #     #", //
+                "           ", //
+                "#   #     #", //
+                "#   #     #", //
+                "#   #     #", //
+                " ###       ", //
+            ],
+        );
+
+        let style = SevenSegmentStyle {
+            unsupported_character: UnsupportedCharacterPolicy::Replace(Segments::all()),
+            ..style
+        };
+        test_digits(
+            style,
+            "0W",
+            &[
+                " ###   ### ", //
+                "#   # #   #", //
+                "#   # #   #", //
+                "#   # #   #", //
+                "       ### ", //
+                "#   # #   #", //
+                "#   # #   #", //
+                "#   # #   #", //
+                " ###   ### ", //
+            ],
+        );
+
+        let style = SevenSegmentStyle {
+            unsupported_character: UnsupportedCharacterPolicy::ErrorPattern,
+            ..style
+        };
+        test_digits(
+            style,
+            "W1",
+            &[
+                " ###       ", //
+                "          #", //
+                "          #", //
+                "          #", //
+                " ###       ", //
+                "          #", //
+                "          #", //
+                "          #", //
+                " ###       ", //
+            ],
+        );
+    }
 }
@@ -1,4 +1,7 @@
-use crate::SevenSegmentStyle;
+use crate::{
+    interpolate::Blending, Bevel, DropShadow, Ghost, Glow, Interpolate, SegmentFill,
+    SegmentRendering, SevenSegmentStyle, TextFlow, UnsupportedCharacterPolicy,
+};
 use embedded_graphics::prelude::*;
 
 /// Seven-segment character style builder.
@@ -8,8 +11,12 @@ pub struct SevenSegmentStyleBuilder<C> {
 }
 
 impl<C: PixelColor> SevenSegmentStyleBuilder<C> {
-    /// Creates a new builder.
-    pub fn new() -> Self {
+    /// Creates a new builder for a color type which doesn't implement [`Interpolate`].
+    ///
+    /// Styles created by this builder don't interpolate colors. Effects which blend two colors
+    /// use the nearer of both colors and the brightness has no effect. Use [`new`](Self::new)
+    /// for color types which implement [`Interpolate`].
+    pub fn without_interpolation() -> Self {
         // TODO: set better default values
         // TODO: add default values to docs
         Self {
@@ -18,7 +25,22 @@ impl<C: PixelColor> SevenSegmentStyleBuilder<C> {
                 digit_spacing: 5,
                 segment_width: 3,
                 segment_color: None,
+                segment_fill: None,
                 inactive_segment_color: None,
+                ghost: None,
+                segment_rendering: SegmentRendering::Filled,
+                inactive_segment_rendering: SegmentRendering::Filled,
+                background_color: None,
+                glow: None,
+                drop_shadow: None,
+                bevel: None,
+                text_flow: TextFlow::Horizontal,
+                cell_width: None,
+                proportional: false,
+                line_spacing: None,
+                unsupported_character: UnsupportedCharacterPolicy::Blank,
+                brightness: u8::MAX,
+                blending: Blending::stepped(),
             },
         }
     }
@@ -58,6 +80,13 @@ impl<C: PixelColor> SevenSegmentStyleBuilder<C> {
         self
     }
 
+    /// Removes the segment fill.
+    pub fn reset_segment_fill(mut self) -> Self {
+        self.style.segment_fill = None;
+
+        self
+    }
+
     /// Sets the inactive segment color.
     pub fn inactive_segment_color(mut self, inactive_segment_color: C) -> Self {
         self.style.inactive_segment_color = Some(inactive_segment_color);
@@ -72,13 +101,194 @@ impl<C: PixelColor> SevenSegmentStyleBuilder<C> {
         self
     }
 
+    /// Disables ghost segments.
+    pub fn reset_ghost(mut self) -> Self {
+        self.style.ghost = None;
+
+        self
+    }
+
+    /// Sets the rendering mode of active segments.
+    pub fn segment_rendering(mut self, segment_rendering: SegmentRendering) -> Self {
+        self.style.segment_rendering = segment_rendering;
+
+        self
+    }
+
+    /// Sets the rendering mode of inactive segments.
+    pub fn inactive_segment_rendering(
+        mut self,
+        inactive_segment_rendering: SegmentRendering,
+    ) -> Self {
+        self.style.inactive_segment_rendering = inactive_segment_rendering;
+
+        self
+    }
+
+    /// Sets the background color.
+    pub fn background_color(mut self, background_color: C) -> Self {
+        self.style.background_color = Some(background_color);
+
+        self
+    }
+
+    /// Resets the background color to transparent.
+    pub fn reset_background_color(mut self) -> Self {
+        self.style.background_color = None;
+
+        self
+    }
+
+    /// Removes the glow effect.
+    pub fn reset_glow(mut self) -> Self {
+        self.style.glow = None;
+
+        self
+    }
+
+    /// Sets the drop shadow.
+    pub fn drop_shadow(mut self, drop_shadow: DropShadow<C>) -> Self {
+        self.style.drop_shadow = Some(drop_shadow);
+
+        self
+    }
+
+    /// Removes the drop shadow.
+    pub fn reset_drop_shadow(mut self) -> Self {
+        self.style.drop_shadow = None;
+
+        self
+    }
+
+    /// Sets the bevel.
+    pub fn bevel(mut self, bevel: Bevel<C>) -> Self {
+        self.style.bevel = Some(bevel);
+
+        self
+    }
+
+    /// Removes the bevel.
+    pub fn reset_bevel(mut self) -> Self {
+        self.style.bevel = None;
+
+        self
+    }
+
+    /// Sets the text flow direction.
+    pub fn text_flow(mut self, text_flow: TextFlow) -> Self {
+        self.style.text_flow = text_flow;
+
+        self
+    }
+
+    /// Sets the fixed cell width.
+    ///
+    /// See [`SevenSegmentStyle::cell_width`] for details.
+    pub fn cell_width(mut self, cell_width: u32) -> Self {
+        self.style.cell_width = Some(cell_width);
+
+        self
+    }
+
+    /// Resets the fixed cell width to use the natural character widths.
+    pub fn reset_cell_width(mut self) -> Self {
+        self.style.cell_width = None;
+
+        self
+    }
+
+    /// Enables or disables proportional digits.
+    ///
+    /// See [`SevenSegmentStyle::proportional`] for details.
+    pub fn proportional(mut self, proportional: bool) -> Self {
+        self.style.proportional = proportional;
+
+        self
+    }
+
+    /// Sets the line spacing.
+    pub fn line_spacing(mut self, line_spacing: u32) -> Self {
+        self.style.line_spacing = Some(line_spacing);
+
+        self
+    }
+
+    /// Resets the line spacing to use the digit spacing.
+    pub fn reset_line_spacing(mut self) -> Self {
+        self.style.line_spacing = None;
+
+        self
+    }
+
+    /// Sets the unsupported character policy.
+    pub fn unsupported_character(
+        mut self,
+        unsupported_character: UnsupportedCharacterPolicy,
+    ) -> Self {
+        self.style.unsupported_character = unsupported_character;
+
+        self
+    }
+
     /// Builds the text style.
     pub fn build(self) -> SevenSegmentStyle<C> {
         self.style
     }
 }
 
-impl<C: PixelColor> Default for SevenSegmentStyleBuilder<C> {
+/// Effects which blend colors.
+///
+/// These effects require a color type which implements [`Interpolate`].
+impl<C: Interpolate> SevenSegmentStyleBuilder<C> {
+    /// Creates a new builder.
+    ///
+    /// Styles created by this builder use the [`Interpolate`] implementation of the color type
+    /// for all effects which blend colors, including effects which are enabled by changing the
+    /// fields of a built style.
+    pub fn new() -> Self {
+        let mut builder = Self::without_interpolation();
+        builder.style.blending = Blending::interpolated();
+
+        builder
+    }
+
+    /// Sets the segment fill.
+    ///
+    /// The segment fill takes precedence over the segment color.
+    pub fn segment_fill(mut self, segment_fill: SegmentFill<C>) -> Self {
+        self.style.segment_fill = Some(segment_fill);
+
+        self
+    }
+
+    /// Enables ghost segments.
+    ///
+    /// Ghost segments take precedence over the inactive segment color.
+    pub fn ghost(mut self, ghost: Ghost<C>) -> Self {
+        self.style.ghost = Some(ghost);
+
+        self
+    }
+
+    /// Sets the glow effect.
+    pub fn glow(mut self, glow: Glow<C>) -> Self {
+        self.style.glow = Some(glow);
+
+        self
+    }
+
+    /// Sets the brightness.
+    ///
+    /// The brightness is applied to active and inactive segments. `255` is full brightness and
+    /// `0` turns all segments black.
+    pub fn brightness(mut self, brightness: u8) -> Self {
+        self.style.brightness = brightness;
+
+        self
+    }
+}
+
+impl<C: Interpolate> Default for SevenSegmentStyleBuilder<C> {
     fn default() -> Self {
         Self::new()
     }
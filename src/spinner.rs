@@ -0,0 +1,239 @@
+use crate::Segments;
+
+/// Spinner animation pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum SpinnerPattern {
+    /// A single segment running around the outer edge of all digits.
+    #[default]
+    Loop,
+
+    /// A single segment moving along a figure eight in each digit.
+    FigureEight,
+
+    /// A snake of three segments running around the outer edge of all digits.
+    Snake,
+
+    /// A vertical bar bouncing between the left and right edges of all digits.
+    BouncingBar,
+}
+
+/// Spinning progress indicator.
+///
+/// A spinner is an infinite iterator over animation frames. Each frame contains the active
+/// segments of `N` adjacent digits, which can be drawn using the [`Digit`](crate::Digit)
+/// drawable.
+///
+/// # Examples
+///
+/// ```
+/// use eg_seven_segment::{Segments, Spinner, SpinnerPattern};
+///
+/// let mut spinner = Spinner::<2>::new(SpinnerPattern::Loop);
+///
+/// assert_eq!(spinner.next(), Some([Segments::A, Segments::empty()]));
+/// assert_eq!(spinner.next(), Some([Segments::empty(), Segments::A]));
+/// assert_eq!(spinner.next(), Some([Segments::empty(), Segments::B]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Spinner<const N: usize> {
+    pattern: SpinnerPattern,
+    frame: usize,
+}
+
+impl<const N: usize> Spinner<N> {
+    /// Creates a new spinner.
+    pub const fn new(pattern: SpinnerPattern) -> Self {
+        Self { pattern, frame: 0 }
+    }
+
+    /// Returns the number of frames in one animation cycle.
+    pub const fn frame_count(&self) -> usize {
+        match self.pattern {
+            SpinnerPattern::Loop | SpinnerPattern::Snake => 2 * N + 4,
+            SpinnerPattern::FigureEight => FIGURE_EIGHT.len(),
+            SpinnerPattern::BouncingBar if N == 0 => 1,
+            SpinnerPattern::BouncingBar => 2 * (2 * N - 1),
+        }
+    }
+
+    /// Returns the frame with the given index.
+    ///
+    /// The index wraps around at the end of each animation cycle.
+    pub fn frame(&self, index: usize) -> [Segments; N] {
+        let mut digits = [Segments::empty(); N];
+        if N == 0 {
+            return digits;
+        }
+
+        let index = index % self.frame_count();
+
+        match self.pattern {
+            SpinnerPattern::Loop => {
+                let (digit, segment) = perimeter::<N>(index);
+                digits[digit] |= segment;
+            }
+            SpinnerPattern::FigureEight => {
+                digits = [FIGURE_EIGHT[index]; N];
+            }
+            SpinnerPattern::Snake => {
+                let count = self.frame_count();
+
+                for offset in 0..SNAKE_LENGTH.min(count) {
+                    let (digit, segment) = perimeter::<N>((index + count - offset) % count);
+                    digits[digit] |= segment;
+                }
+            }
+            SpinnerPattern::BouncingBar => {
+                let columns = 2 * N;
+                let column = if index < columns {
+                    index
+                } else {
+                    self.frame_count() - index
+                };
+
+                digits[column / 2] = if column % 2 == 0 {
+                    Segments::E | Segments::F
+                } else {
+                    Segments::B | Segments::C
+                };
+            }
+        }
+
+        digits
+    }
+}
+
+impl<const N: usize> Iterator for Spinner<N> {
+    type Item = [Segments; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.frame(self.frame);
+        self.frame = (self.frame + 1) % self.frame_count();
+
+        Some(frame)
+    }
+}
+
+/// The number of segments in a snake.
+const SNAKE_LENGTH: usize = 3;
+
+/// Figure eight path.
+const FIGURE_EIGHT: [Segments; 8] = [
+    Segments::A,
+    Segments::B,
+    Segments::G,
+    Segments::E,
+    Segments::D,
+    Segments::C,
+    Segments::G,
+    Segments::F,
+];
+
+/// Returns the segment at the given index of the clockwise path around the outer edge of `N`
+/// digits.
+///
+/// The path starts at the `A` segment of the leftmost digit and has a length of `2 * N + 4`.
+fn perimeter<const N: usize>(index: usize) -> (usize, Segments) {
+    match index {
+        i if i < N => (i, Segments::A),
+        i if i == N => (N - 1, Segments::B),
+        i if i == N + 1 => (N - 1, Segments::C),
+        i if i < 2 * N + 2 => (2 * N + 1 - i, Segments::D),
+        i if i == 2 * N + 2 => (0, Segments::E),
+        _ => (0, Segments::F),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NONE: Segments = Segments::empty();
+
+    fn cycle<const N: usize>(pattern: SpinnerPattern) -> impl Iterator<Item = [Segments; N]> {
+        let spinner = Spinner::<N>::new(pattern);
+
+        spinner.take(spinner.frame_count())
+    }
+
+    #[test]
+    fn single_digit_loop() {
+        assert!(cycle::<1>(SpinnerPattern::Loop).eq([
+            [Segments::A],
+            [Segments::B],
+            [Segments::C],
+            [Segments::D],
+            [Segments::E],
+            [Segments::F],
+        ]));
+    }
+
+    #[test]
+    fn multi_digit_loop() {
+        assert!(cycle::<2>(SpinnerPattern::Loop).eq([
+            [Segments::A, NONE],
+            [NONE, Segments::A],
+            [NONE, Segments::B],
+            [NONE, Segments::C],
+            [NONE, Segments::D],
+            [Segments::D, NONE],
+            [Segments::E, NONE],
+            [Segments::F, NONE],
+        ]));
+    }
+
+    #[test]
+    fn figure_eight() {
+        assert!(cycle::<2>(SpinnerPattern::FigureEight)
+            .map(|[a, b]| {
+                assert_eq!(a, b);
+                a
+            })
+            .eq(FIGURE_EIGHT));
+    }
+
+    #[test]
+    fn snake() {
+        assert!(cycle::<2>(SpinnerPattern::Snake).eq([
+            [Segments::A | Segments::E | Segments::F, NONE],
+            [Segments::A | Segments::F, Segments::A],
+            [Segments::A, Segments::A | Segments::B],
+            [NONE, Segments::A | Segments::B | Segments::C],
+            [NONE, Segments::B | Segments::C | Segments::D],
+            [Segments::D, Segments::C | Segments::D],
+            [Segments::D | Segments::E, Segments::D],
+            [Segments::D | Segments::E | Segments::F, NONE],
+        ]));
+    }
+
+    #[test]
+    fn bouncing_bar() {
+        const LEFT: Segments = Segments::E.union(Segments::F);
+        const RIGHT: Segments = Segments::B.union(Segments::C);
+
+        assert!(cycle::<2>(SpinnerPattern::BouncingBar).eq([
+            [LEFT, NONE],
+            [RIGHT, NONE],
+            [NONE, LEFT],
+            [NONE, RIGHT],
+            [NONE, LEFT],
+            [RIGHT, NONE],
+        ]));
+
+        assert!(cycle::<1>(SpinnerPattern::BouncingBar).eq([[LEFT], [RIGHT]]));
+    }
+
+    #[test]
+    fn repeats() {
+        let mut spinner = Spinner::<1>::new(SpinnerPattern::Loop);
+
+        assert_eq!(spinner.nth(6), Some([Segments::A]));
+    }
+
+    #[test]
+    fn zero_digits() {
+        let mut spinner = Spinner::<0>::new(SpinnerPattern::Snake);
+
+        assert_eq!(spinner.next(), Some([]));
+    }
+}
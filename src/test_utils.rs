@@ -0,0 +1,33 @@
+//! Helpers shared by the widget tests.
+
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+
+use crate::{glyph::Glyph, Segments, SevenSegmentStyle, SevenSegmentStyleBuilder};
+
+/// Returns the glyphs of a string without hidden segments.
+pub fn text(text: &str) -> impl Iterator<Item = (Glyph, Segments)> + '_ {
+    text.chars().map(|c| (Glyph::from(c), Segments::empty()))
+}
+
+/// Asserts that a sequence of glyphs matches the glyphs of a string.
+#[track_caller]
+pub fn assert_glyphs<I>(glyphs: I, expected: &str)
+where
+    I: IntoIterator<Item = (Glyph, Segments)>,
+{
+    assert!(
+        glyphs.into_iter().eq(text(expected)),
+        "expected {:?}",
+        expected
+    );
+}
+
+/// Returns a small style with 5x9 pixel digits.
+pub fn style() -> SevenSegmentStyle<BinaryColor> {
+    SevenSegmentStyleBuilder::new()
+        .digit_size(Size::new(5, 9))
+        .digit_spacing(1)
+        .segment_width(1)
+        .segment_color(BinaryColor::On)
+        .build()
+}
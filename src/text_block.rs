@@ -0,0 +1,283 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+    text::{renderer::TextRenderer, Alignment, Baseline},
+};
+
+use crate::{SevenSegmentStyle, TextFlow};
+
+/// Multi-line text block.
+///
+/// The text block splits a string into lines at `\n` characters and aligns each line
+/// independently inside the bounding box of the whole block. The distance between lines is the
+/// digit height plus the line spacing of the style.
+///
+/// In vertical text flow the lines are placed as columns from left to right, separated by the
+/// digit width plus the line spacing, and the alignment is applied vertically.
+///
+/// The position is the top left corner of the block.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{
+///     prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable, text::Alignment,
+/// };
+/// use eg_seven_segment::{SevenSegmentStyleBuilder, TextBlock};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .line_spacing(10)
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// TextBlock::new("12:30\n5.0", Point::new(5, 5))
+///     .with_alignment(Alignment::Right)
+///     .draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextBlock<'a> {
+    /// The text.
+    pub text: &'a str,
+
+    /// The position.
+    pub position: Point,
+
+    /// The alignment of the lines.
+    pub alignment: Alignment,
+}
+
+impl<'a> TextBlock<'a> {
+    /// Creates a new left aligned text block.
+    pub fn new(text: &'a str, position: Point) -> Self {
+        Self {
+            text,
+            position,
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Sets the alignment.
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+
+        self
+    }
+
+    /// Returns the bounding box of the block.
+    pub fn bounding_box<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> Rectangle {
+        let lines = self.lines().count() as u32;
+        let length = self.max_line_length(style);
+        let depth = match lines {
+            0 => 0,
+            _ => (lines - 1) * style.line_pitch() + line_depth(style),
+        };
+
+        let size = match style.text_flow {
+            TextFlow::Horizontal => Size::new(length, depth),
+            TextFlow::Vertical => Size::new(depth, length),
+        };
+
+        Rectangle::new(self.position, size)
+    }
+
+    /// Returns an iterator over the lines.
+    fn lines(&self) -> impl Iterator<Item = &'a str> {
+        self.text.lines()
+    }
+
+    /// Returns the size of a line along the text flow direction.
+    fn line_length<C: PixelColor>(style: &SevenSegmentStyle<C>, line: &str) -> u32 {
+        let size = style
+            .measure_string(line, Point::zero(), Baseline::Top)
+            .bounding_box
+            .size;
+
+        match style.text_flow {
+            TextFlow::Horizontal => size.width,
+            TextFlow::Vertical => size.height,
+        }
+    }
+
+    /// Returns the length of the longest line.
+    fn max_line_length<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> u32 {
+        self.lines()
+            .map(|line| Self::line_length(style, line))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Returns the size of a line perpendicular to the text flow direction.
+fn line_depth<C>(style: &SevenSegmentStyle<C>) -> u32 {
+    match style.text_flow {
+        TextFlow::Horizontal => style.digit_size.height,
+        TextFlow::Vertical => style.digit_size.width,
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for TextBlock<'_> {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let block_length = self.max_line_length(style);
+        let mut next = self.position;
+
+        for (index, line) in self.lines().enumerate() {
+            let free = block_length - Self::line_length(style, line);
+            let offset = match self.alignment {
+                Alignment::Left => 0,
+                Alignment::Center => free / 2,
+                Alignment::Right => free,
+            } as i32;
+            let line_offset = (index as u32 * style.line_pitch()) as i32;
+
+            let position = match style.text_flow {
+                TextFlow::Horizontal => self.position + Point::new(offset, line_offset),
+                TextFlow::Vertical => self.position + Point::new(line_offset, offset),
+            };
+
+            next = style.draw_string(line, position, Baseline::Top, target)?;
+        }
+
+        Ok(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    fn style() -> SevenSegmentStyle<BinaryColor> {
+        SevenSegmentStyle {
+            line_spacing: Some(2),
+            ..test_utils::style()
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let block = TextBlock::new("1:23\n4\n56", Point::new(1, 2));
+        assert_eq!(
+            block.bounding_box(&style()),
+            Rectangle::new(Point::new(1, 2), Size::new(19, 31))
+        );
+
+        let block = TextBlock::new("", Point::zero());
+        assert_eq!(
+            block.bounding_box(&style()),
+            Rectangle::new(Point::zero(), Size::zero())
+        );
+
+        let style = SevenSegmentStyle {
+            text_flow: TextFlow::Vertical,
+            ..style()
+        };
+        let block = TextBlock::new("12\n3", Point::zero());
+        assert_eq!(
+            block.bounding_box(&style),
+            Rectangle::new(Point::zero(), Size::new(12, 19))
+        );
+    }
+
+    #[test]
+    fn draw() {
+        let block = TextBlock::new("12\n3", Point::zero());
+
+        let mut display = MockDisplay::new();
+        block.draw_styled(&style(), &mut display).unwrap();
+        display.assert_pattern(&[
+            "       ### ", //
+            "    #     #", //
+            "    #     #", //
+            "    #     #", //
+            "       ### ", //
+            "    # #    ", //
+            "    # #    ", //
+            "    # #    ", //
+            "       ### ", //
+            "           ", //
+            "           ", //
+            " ###       ", //
+            "    #      ", //
+            "    #      ", //
+            "    #      ", //
+            " ###       ", //
+            "    #      ", //
+            "    #      ", //
+            "    #      ", //
+            " ###       ", //
+        ]);
+
+        let mut display = MockDisplay::new();
+        block
+            .with_alignment(Alignment::Center)
+            .draw_styled(&style(), &mut display)
+            .unwrap();
+        display.assert_pattern(&[
+            "       ### ", //
+            "    #     #", //
+            "    #     #", //
+            "    #     #", //
+            "       ### ", //
+            "    # #    ", //
+            "    # #    ", //
+            "    # #    ", //
+            "       ### ", //
+            "           ", //
+            "           ", //
+            "    ###    ", //
+            "       #   ", //
+            "       #   ", //
+            "       #   ", //
+            "    ###    ", //
+            "       #   ", //
+            "       #   ", //
+            "       #   ", //
+            "    ###    ", //
+        ]);
+
+        let mut display = MockDisplay::new();
+        block
+            .with_alignment(Alignment::Right)
+            .draw_styled(&style(), &mut display)
+            .unwrap();
+        display.assert_pattern(&[
+            "       ### ", //
+            "    #     #", //
+            "    #     #", //
+            "    #     #", //
+            "       ### ", //
+            "    # #    ", //
+            "    # #    ", //
+            "    # #    ", //
+            "       ### ", //
+            "           ", //
+            "           ", //
+            "       ### ", //
+            "          #", //
+            "          #", //
+            "          #", //
+            "       ### ", //
+            "          #", //
+            "          #", //
+            "          #", //
+            "       ### ", //
+        ]);
+    }
+}
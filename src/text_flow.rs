@@ -0,0 +1,18 @@
+/// Text flow direction.
+///
+/// The text flow defines in which direction consecutive characters are placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TextFlow {
+    /// Characters are placed from left to right.
+    #[default]
+    Horizontal,
+
+    /// Characters are stacked from top to bottom.
+    ///
+    /// Multi-line strings aren't supported by embedded-graphics [`Text`], which always places
+    /// the next line below the previous one and would overlap the column. Use a
+    /// [`TextBlock`](crate::TextBlock) to draw multiple columns instead.
+    ///
+    /// [`Text`]: embedded_graphics::text::Text
+    Vertical,
+}
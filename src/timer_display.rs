@@ -0,0 +1,456 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StyledDrawable},
+    text::Baseline,
+};
+
+use crate::{
+    glyph::Glyph,
+    glyph_buffer::{GlyphBuffer, Padding},
+    Blink, LeadingZeros, Segments, SevenSegmentStyle,
+};
+
+/// Timer overflow behavior.
+///
+/// Timers can display durations of up to `99:59:59`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum TimerOverflow {
+    /// Longer durations are displayed as `99:59:59`.
+    #[default]
+    Saturate,
+
+    /// Longer durations wrap around to `00:00.00`.
+    ///
+    /// A countdown only displays zero after it has expired, which means that remaining times
+    /// which are a multiple of 100 hours are displayed as `99:59:59`.
+    Wrap,
+}
+
+/// Stopwatch.
+///
+/// The stopwatch displays the elapsed time in the format `MM:SS.cc` for durations less than one
+/// hour and in the format `HH:MM:SS` for longer durations. The leading hour digit of durations
+/// of less than ten hours is displayed according to the leading zeros mode, which defaults to
+/// [`LeadingZeros::Inactive`]. The minutes of the `MM:SS.cc` format are always zero padded. The
+/// displayed time is truncated to the resolution of the format.
+///
+/// The position is the top left corner of the first digit.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888, primitives::StyledDrawable};
+/// use eg_seven_segment::{SevenSegmentStyleBuilder, StopwatchDisplay};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::GREEN)
+///     .build();
+///
+/// // Draws "01:02.34".
+/// StopwatchDisplay::new(62_345, Point::new(5, 5)).draw_styled(&style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StopwatchDisplay {
+    /// The elapsed time in milliseconds.
+    pub elapsed_ms: u32,
+
+    /// The position.
+    pub position: Point,
+
+    /// The overflow behavior.
+    pub overflow: TimerOverflow,
+
+    /// The display mode of the leading hour zero.
+    pub leading_zeros: LeadingZeros,
+}
+
+impl StopwatchDisplay {
+    /// Creates a new stopwatch.
+    pub fn new(elapsed_ms: u32, position: Point) -> Self {
+        Self {
+            elapsed_ms,
+            position,
+            overflow: TimerOverflow::default(),
+            leading_zeros: LeadingZeros::default(),
+        }
+    }
+
+    /// Sets the overflow behavior.
+    pub fn with_overflow(mut self, overflow: TimerOverflow) -> Self {
+        self.overflow = overflow;
+
+        self
+    }
+
+    /// Sets the display mode of the leading hour zero.
+    pub fn with_leading_zeros(mut self, leading_zeros: LeadingZeros) -> Self {
+        self.leading_zeros = leading_zeros;
+
+        self
+    }
+
+    /// Returns the bounding box of the stopwatch.
+    pub fn bounding_box<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> Rectangle {
+        self.glyphs().bounding_box(self.position, style)
+    }
+
+    /// Formats the stopwatch.
+    fn glyphs(&self) -> GlyphBuffer<TIMER_GLYPHS> {
+        format_duration(self.elapsed_ms, self.overflow, self.leading_zeros, false)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for StopwatchDisplay {
+    type Color = C;
+    type Output = Point;
+
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        style.draw_glyphs(self.glyphs().iter(), self.position, Baseline::Top, target)
+    }
+}
+
+/// Countdown timer.
+///
+/// The countdown displays the remaining time in the same formats as the [`StopwatchDisplay`].
+/// The displayed time is rounded up to the resolution of the format, to make sure that zero is
+/// only displayed after the countdown has expired.
+///
+/// After the countdown has expired, [`draw_styled_at`](Self::draw_styled_at) blinks the
+/// display.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), core::convert::Infallible> {
+/// use embedded_graphics::{prelude::*, pixelcolor::Rgb888};
+/// use eg_seven_segment::{CountdownDisplay, SevenSegmentStyleBuilder};
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::new();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let style = SevenSegmentStyleBuilder::new()
+///     .segment_color(Rgb888::RED)
+///     .build();
+///
+/// let duration_ms: u32 = 10_000;
+/// let time_ms = 12_345;
+///
+/// CountdownDisplay::new(duration_ms.saturating_sub(time_ms), Point::new(5, 5))
+///     .draw_styled_at(time_ms, &style, &mut display)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CountdownDisplay {
+    /// The remaining time in milliseconds.
+    pub remaining_ms: u32,
+
+    /// The position.
+    pub position: Point,
+
+    /// The overflow behavior.
+    pub overflow: TimerOverflow,
+
+    /// The display mode of the leading hour zero.
+    pub leading_zeros: LeadingZeros,
+
+    /// The blink timing after the countdown has expired.
+    pub blink: Blink,
+}
+
+impl CountdownDisplay {
+    /// Creates a new countdown timer.
+    pub fn new(remaining_ms: u32, position: Point) -> Self {
+        Self {
+            remaining_ms,
+            position,
+            overflow: TimerOverflow::default(),
+            leading_zeros: LeadingZeros::default(),
+            blink: Blink::default(),
+        }
+    }
+
+    /// Sets the overflow behavior.
+    pub fn with_overflow(mut self, overflow: TimerOverflow) -> Self {
+        self.overflow = overflow;
+
+        self
+    }
+
+    /// Sets the display mode of the leading hour zero.
+    pub fn with_leading_zeros(mut self, leading_zeros: LeadingZeros) -> Self {
+        self.leading_zeros = leading_zeros;
+
+        self
+    }
+
+    /// Sets the blink timing after the countdown has expired.
+    pub fn with_blink(mut self, blink: Blink) -> Self {
+        self.blink = blink;
+
+        self
+    }
+
+    /// Returns `true` if the countdown has expired.
+    pub fn is_expired(&self) -> bool {
+        self.remaining_ms == 0
+    }
+
+    /// Returns the bounding box of the countdown.
+    pub fn bounding_box<C: PixelColor>(&self, style: &SevenSegmentStyle<C>) -> Rectangle {
+        self.glyphs().bounding_box(self.position, style)
+    }
+
+    /// Draws the countdown at the given point in time.
+    ///
+    /// Returns the position of the next character.
+    pub fn draw_styled_at<C, D>(
+        &self,
+        time_ms: u32,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        C: PixelColor,
+        D: DrawTarget<Color = C>,
+    {
+        let hidden = if self.is_expired() && !self.blink.is_visible(time_ms) {
+            Segments::all()
+        } else {
+            Segments::empty()
+        };
+
+        let glyphs = self.glyphs();
+        let glyphs = glyphs.iter().map(|(glyph, _)| (glyph, hidden));
+
+        style.draw_glyphs(glyphs, self.position, Baseline::Top, target)
+    }
+
+    /// Formats the countdown.
+    fn glyphs(&self) -> GlyphBuffer<TIMER_GLYPHS> {
+        format_duration(self.remaining_ms, self.overflow, self.leading_zeros, true)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<SevenSegmentStyle<C>> for CountdownDisplay {
+    type Color = C;
+    type Output = Point;
+
+    /// Draws the countdown with all elements visible.
+    fn draw_styled<D>(
+        &self,
+        style: &SevenSegmentStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        style.draw_glyphs(self.glyphs().iter(), self.position, Baseline::Top, target)
+    }
+}
+
+/// The maximum number of glyphs in a timer.
+const TIMER_GLYPHS: usize = 8;
+
+/// The number of milliseconds in the displayable range.
+const RANGE_MS: u32 = 100 * 60 * 60 * 1000;
+
+/// Formats a duration.
+///
+/// If `round_up` is `true` the duration is rounded up to the resolution of the format,
+/// otherwise it is truncated.
+fn format_duration(
+    ms: u32,
+    overflow: TimerOverflow,
+    leading_zeros: LeadingZeros,
+    round_up: bool,
+) -> GlyphBuffer<TIMER_GLYPHS> {
+    let ms = match overflow {
+        TimerOverflow::Saturate => ms,
+        // Rounded up durations are only wrapped to zero if they are zero.
+        TimerOverflow::Wrap if round_up && ms > 0 => (ms - 1) % RANGE_MS + 1,
+        TimerOverflow::Wrap => ms % RANGE_MS,
+    };
+    let round = |divisor: u32| {
+        if round_up {
+            ms.div_ceil(divisor)
+        } else {
+            ms / divisor
+        }
+    };
+
+    let mut glyphs = GlyphBuffer::new();
+
+    let centiseconds = round(10);
+    if centiseconds < 60 * 60 * 100 {
+        glyphs.push_number(centiseconds / 6000, 10, 2, Padding::Zeros);
+        glyphs.push(Glyph::Colon);
+        glyphs.push_number(centiseconds / 100 % 60, 10, 2, Padding::Zeros);
+        glyphs.push(Glyph::DecimalPoint);
+        glyphs.push_number(centiseconds % 100, 10, 2, Padding::Zeros);
+    } else {
+        let seconds = round(1000).min(RANGE_MS / 1000 - 1);
+
+        glyphs.push_number(seconds / 3600, 10, 2, leading_zeros.padding());
+        glyphs.push(Glyph::Colon);
+        glyphs.push_number(seconds / 60 % 60, 10, 2, Padding::Zeros);
+        glyphs.push(Glyph::Colon);
+        glyphs.push_number(seconds % 60, 10, 2, Padding::Zeros);
+    }
+
+    glyphs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_glyphs, style};
+    use embedded_graphics::{mock_display::MockDisplay, pixelcolor::BinaryColor};
+
+    fn assert_timer<const N: usize>(glyphs: GlyphBuffer<N>, expected: &str) {
+        assert_glyphs(glyphs.iter(), expected);
+    }
+
+    const HOUR: u32 = 60 * 60 * 1000;
+
+    #[test]
+    fn stopwatch() {
+        let stopwatch = |ms| StopwatchDisplay::new(ms, Point::zero()).glyphs();
+
+        assert_timer(stopwatch(0), "00:00.00");
+        assert_timer(stopwatch(9), "00:00.00");
+        assert_timer(stopwatch(62_345), "01:02.34");
+        assert_timer(stopwatch(HOUR - 1), "59:59.99");
+        assert_timer(stopwatch(HOUR), " 1:00:00");
+        assert_timer(stopwatch(12 * HOUR + 345_678), "12:05:45");
+        assert_timer(stopwatch(100 * HOUR - 1), "99:59:59");
+    }
+
+    #[test]
+    fn leading_zeros() {
+        let stopwatch = |ms, leading_zeros| {
+            StopwatchDisplay::new(ms, Point::zero())
+                .with_leading_zeros(leading_zeros)
+                .glyphs()
+        };
+
+        assert_timer(stopwatch(HOUR, LeadingZeros::Show), "01:00:00");
+        assert_timer(stopwatch(HOUR, LeadingZeros::Inactive), " 1:00:00");
+        assert_timer(stopwatch(62_345, LeadingZeros::Inactive), "01:02.34");
+        assert_eq!(
+            stopwatch(HOUR, LeadingZeros::Suppress).iter().next(),
+            Some((Glyph::Empty, Segments::empty()))
+        );
+
+        let countdown = CountdownDisplay::new(HOUR + 1, Point::zero())
+            .with_leading_zeros(LeadingZeros::Show)
+            .glyphs();
+        assert_timer(countdown, "01:00:01");
+    }
+
+    #[test]
+    fn stopwatch_overflow() {
+        let stopwatch = |ms, overflow| {
+            StopwatchDisplay::new(ms, Point::zero())
+                .with_overflow(overflow)
+                .glyphs()
+        };
+
+        assert_timer(stopwatch(100 * HOUR, TimerOverflow::Saturate), "99:59:59");
+        assert_timer(stopwatch(u32::MAX, TimerOverflow::Saturate), "99:59:59");
+        assert_timer(stopwatch(100 * HOUR, TimerOverflow::Wrap), "00:00.00");
+        assert_timer(stopwatch(101 * HOUR + 5, TimerOverflow::Wrap), " 1:00:00");
+    }
+
+    #[test]
+    fn countdown() {
+        let countdown = |ms| CountdownDisplay::new(ms, Point::zero()).glyphs();
+
+        assert_timer(countdown(0), "00:00.00");
+        assert_timer(countdown(1), "00:00.01");
+        assert_timer(countdown(62_345), "01:02.35");
+        assert_timer(countdown(HOUR - 5), " 1:00:00");
+        assert_timer(countdown(HOUR + 1), " 1:00:01");
+        assert_timer(countdown(100 * HOUR - 1), "99:59:59");
+    }
+
+    #[test]
+    fn countdown_overflow() {
+        let countdown = |ms, overflow| {
+            CountdownDisplay::new(ms, Point::zero())
+                .with_overflow(overflow)
+                .glyphs()
+        };
+
+        assert_timer(countdown(100 * HOUR, TimerOverflow::Saturate), "99:59:59");
+        assert_timer(countdown(100 * HOUR, TimerOverflow::Wrap), "99:59:59");
+        assert_timer(countdown(100 * HOUR + 1, TimerOverflow::Wrap), "00:00.01");
+        assert_timer(countdown(0, TimerOverflow::Wrap), "00:00.00");
+    }
+
+    #[test]
+    fn countdown_expiry() {
+        let style = SevenSegmentStyle {
+            inactive_segment_color: Some(BinaryColor::Off),
+            ..style()
+        };
+
+        let countdown = CountdownDisplay::new(0, Point::zero()).with_blink(Blink::new(1000, 50));
+        assert!(countdown.is_expired());
+
+        let mut visible = MockDisplay::new();
+        countdown.draw_styled_at(250, &style, &mut visible).unwrap();
+
+        let mut expected = MockDisplay::new();
+        countdown.draw_styled(&style, &mut expected).unwrap();
+        visible.assert_eq(&expected);
+
+        let mut hidden = MockDisplay::new();
+        countdown.draw_styled_at(750, &style, &mut hidden).unwrap();
+        hidden.assert_pattern(&[
+            " ...   ...     ...   ...     ...   ... ", //
+            ".   . .   .   .   . .   .   .   . .   .", //
+            ".   . .   .   .   . .   .   .   . .   .", //
+            ".   . .   .   .   . .   .   .   . .   .", //
+            " ...   ...     ...   ...     ...   ... ", //
+            ".   . .   .   .   . .   .   .   . .   .", //
+            ".   . .   .   .   . .   .   .   . .   .", //
+            ".   . .   .   .   . .   .   .   . .   .", //
+            " ...   ...     ...   ...     ...   ... ", //
+        ]);
+
+        let running = CountdownDisplay::new(500, Point::zero());
+        let mut display = MockDisplay::new();
+        running.draw_styled_at(750, &style, &mut display).unwrap();
+        let mut expected = MockDisplay::new();
+        running.draw_styled(&style, &mut expected).unwrap();
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let style = style();
+
+        assert_eq!(
+            StopwatchDisplay::new(0, Point::new(1, 2)).bounding_box(&style),
+            Rectangle::new(Point::new(1, 2), Size::new(39, 9))
+        );
+        assert_eq!(
+            CountdownDisplay::new(HOUR, Point::zero()).bounding_box(&style),
+            Rectangle::new(Point::zero(), Size::new(39, 9))
+        );
+    }
+}
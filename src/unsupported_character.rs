@@ -0,0 +1,140 @@
+use crate::{glyph::Glyph, Segments};
+
+/// Error returned for characters which can't be displayed with seven segments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnsupportedCharacter(pub char);
+
+/// Unsupported character policy.
+///
+/// The policy defines how characters which can't be displayed with seven segments are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum UnsupportedCharacterPolicy {
+    /// Unsupported characters are drawn as blank digits.
+    ///
+    /// Only the background is drawn and inactive segments aren't shown.
+    #[default]
+    Blank,
+
+    /// Unsupported characters are skipped without advancing the position.
+    Skip,
+
+    /// Unsupported characters are replaced by a digit with the given segments.
+    Replace(Segments),
+
+    /// Unsupported characters are drawn as a digit with three horizontal bars.
+    ///
+    /// This pattern doesn't resemble any supported character.
+    ErrorPattern,
+}
+
+impl UnsupportedCharacterPolicy {
+    /// Applies the policy to a glyph.
+    ///
+    /// Returns `None` if the glyph should be skipped.
+    pub(crate) fn apply(self, glyph: Glyph) -> Option<Glyph> {
+        match (glyph, self) {
+            (Glyph::Unsupported, Self::Blank) => Some(Glyph::Empty),
+            (Glyph::Unsupported, Self::Skip) => None,
+            (Glyph::Unsupported, Self::Replace(segments)) => Some(Glyph::Digit(segments)),
+            (Glyph::Unsupported, Self::ErrorPattern) => {
+                Some(Glyph::Digit(Segments::A | Segments::D | Segments::G))
+            }
+            _ => Some(glyph),
+        }
+    }
+}
+
+/// Returns `true` if all characters in the string can be displayed.
+///
+/// See [`check_string`] for details.
+pub fn is_representable(text: &str) -> bool {
+    check_string(text).is_ok()
+}
+
+/// Checks if all characters in the string can be displayed.
+///
+/// Returns an error with the first character which can't be displayed. Line breaks, including
+/// the carriage returns of `"\r\n"` line endings, are ignored, because they start a new line
+/// instead of being drawn. Lines are split in the same way as in a [`TextBlock`](crate::TextBlock).
+///
+/// The result doesn't depend on the [`UnsupportedCharacterPolicy`] of a style, which only
+/// defines how unsupported characters are drawn.
+///
+/// # Examples
+///
+/// ```
+/// use eg_seven_segment::{check_string, UnsupportedCharacter};
+///
+/// assert_eq!(check_string("12:34\n56.7"), Ok(()));
+/// assert_eq!(check_string("HELLO WORLD"), Err(UnsupportedCharacter('W')));
+/// ```
+pub fn check_string(text: &str) -> Result<(), UnsupportedCharacter> {
+    text.lines().try_for_each(check_representable)
+}
+
+/// Checks if all characters in a single line string can be displayed.
+///
+/// Returns an error with the first unsupported character.
+pub(crate) fn check_representable(text: &str) -> Result<(), UnsupportedCharacter> {
+    match text.chars().find(|c| Glyph::from(*c) == Glyph::Unsupported) {
+        Some(c) => Err(UnsupportedCharacter(c)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply() {
+        let digit = Glyph::Digit(Segments::A);
+
+        for policy in [
+            UnsupportedCharacterPolicy::Blank,
+            UnsupportedCharacterPolicy::Skip,
+            UnsupportedCharacterPolicy::Replace(Segments::all()),
+            UnsupportedCharacterPolicy::ErrorPattern,
+        ] {
+            assert_eq!(policy.apply(digit), Some(digit));
+            assert_eq!(policy.apply(Glyph::Colon), Some(Glyph::Colon));
+            assert_eq!(policy.apply(Glyph::Empty), Some(Glyph::Empty));
+        }
+
+        assert_eq!(
+            UnsupportedCharacterPolicy::Blank.apply(Glyph::Unsupported),
+            Some(Glyph::Empty)
+        );
+        assert_eq!(
+            UnsupportedCharacterPolicy::Skip.apply(Glyph::Unsupported),
+            None
+        );
+        assert_eq!(
+            UnsupportedCharacterPolicy::Replace(Segments::all()).apply(Glyph::Unsupported),
+            Some(Glyph::Digit(Segments::all()))
+        );
+        assert_eq!(
+            UnsupportedCharacterPolicy::ErrorPattern.apply(Glyph::Unsupported),
+            Some(Glyph::Digit(Segments::A | Segments::D | Segments::G))
+        );
+    }
+
+    #[test]
+    fn representable() {
+        assert_eq!(check_representable(""), Ok(()));
+        assert_eq!(check_representable("12:34.5°C"), Ok(()));
+        assert_eq!(check_representable("50%"), Err(UnsupportedCharacter('%')));
+        assert_eq!(check_representable("WXYZ"), Err(UnsupportedCharacter('W')));
+        assert_eq!(check_representable("1\n2"), Err(UnsupportedCharacter('\n')));
+    }
+
+    #[test]
+    fn check_lines() {
+        assert!(is_representable("12:34"));
+        assert!(is_representable("12\n34\n"));
+        assert!(!is_representable("WORLD"));
+        assert_eq!(check_string("HELLO\nWORLD"), Err(UnsupportedCharacter('W')));
+        assert_eq!(check_string("1\r\n2\r\n"), Ok(()));
+        assert_eq!(check_string("1\r2"), Err(UnsupportedCharacter('\r')));
+    }
+}